@@ -1,40 +1,39 @@
+use aoc::solution::parse_in;
 use aoc::Solution;
 use itertools::Itertools;
 
 pub struct Day01;
 
-impl Solution<usize, usize> for Day01 {
-    const DAY: u32 = 1;
-    const YEAR: u32 = 2021;
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Sonar Sweep";
     type Input = Vec<i32>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
-        Some(input.iter().tuple_windows().filter(|(a, b)| a < b).count())
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Ok(input.iter().tuple_windows().filter(|(a, b)| a < b).count())
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
-        Some(
-            input
-                .iter()
-                .tuple_windows()
-                .filter(|(a, _, _, d)| a < d)
-                .count(),
-        )
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Ok(input
+            .iter()
+            .tuple_windows()
+            .filter(|(a, _, _, d)| a < d)
+            .count())
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        input
             .trim()
             .lines()
-            .map(|l| l.parse::<i32>().unwrap())
-            .collect())
+            .map(|line| parse_in(input, line))
+            .collect()
     }
 }
 
-fn main() {
-    Day01::run(include_str!("../../data/day01_star1_input"));
-}
+aoc::run!(Day01);
 
 #[cfg(test)]
 mod tests {
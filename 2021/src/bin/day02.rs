@@ -1,3 +1,4 @@
+use aoc::solution::{parse_in, SolutionError};
 use aoc::Solution;
 
 #[derive(Debug)]
@@ -9,13 +10,15 @@ pub enum Instruction {
 pub struct Day02;
 pub struct Day02V2;
 
-impl Solution<i32, i32> for Day02 {
-    const DAY: u32 = 2;
-    const YEAR: u32 = 2021;
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Dive!";
     type Input = Vec<Instruction>;
+    type P1 = i32;
+    type P2 = i32;
 
-    fn part1(input: &Self::Input) -> Option<i32> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<i32> {
         let mut x = 0;
         let mut y = 0;
 
@@ -26,10 +29,10 @@ impl Solution<i32, i32> for Day02 {
             }
         }
 
-        Some(x * y)
+        Ok(x * y)
     }
 
-    fn part2(input: &Self::Input) -> Option<i32> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<i32> {
         let mut x = 0;
         let mut y = 0;
         let mut aim = 0;
@@ -44,21 +47,23 @@ impl Solution<i32, i32> for Day02 {
             }
         }
 
-        Some(x * y)
+        Ok(x * y)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(parse(&input))
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        parse(input)
     }
 }
 
-impl Solution<i32, i32> for Day02V2 {
-    const DAY: u32 = 2;
-    const YEAR: u32 = 2021;
+impl Solution for Day02V2 {
+    const DAY: u8 = 2;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Dive! (Implementation V2)";
     type Input = Vec<Instruction>;
+    type P1 = i32;
+    type P2 = i32;
 
-    fn part1(input: &Self::Input) -> Option<i32> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<i32> {
         let (x, y) = input
             .iter()
             .fold((0, 0), |(x, y), instruction| match instruction {
@@ -66,10 +71,10 @@ impl Solution<i32, i32> for Day02V2 {
                 Instruction::Aim(yi) => (x, y + yi),
             });
 
-        Some(x * y)
+        Ok(x * y)
     }
 
-    fn part2(input: &Self::Input) -> Option<i32> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<i32> {
         let (x, y, _) =
             input
                 .iter()
@@ -78,36 +83,35 @@ impl Solution<i32, i32> for Day02V2 {
                     Instruction::Aim(yi) => (x, y, aim + yi),
                 });
 
-        Some(x * y)
+        Ok(x * y)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(parse(&input))
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        parse(input)
     }
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+fn parse(input: &str) -> aoc::solution::Result<Vec<Instruction>> {
     input
         .trim()
         .lines()
-        .filter_map(|line| {
-            let pair: Vec<&str> = line.split_whitespace().collect();
-            let value: i32 = pair[1].parse().unwrap();
-
-            return match pair[0] {
-                "down" => Some(Instruction::Aim(value)),
-                "up" => Some(Instruction::Aim(-value)),
-                "forward" => Some(Instruction::Forward(value)),
-                _ => None,
-            };
+        .map(|line| {
+            let (direction, value) = line
+                .split_once(' ')
+                .ok_or_else(|| SolutionError::invalid(input, line))?;
+            let value: i32 = parse_in(input, value)?;
+
+            match direction {
+                "down" => Ok(Instruction::Aim(value)),
+                "up" => Ok(Instruction::Aim(-value)),
+                "forward" => Ok(Instruction::Forward(value)),
+                _ => Err(SolutionError::invalid(input, direction)),
+            }
         })
         .collect()
 }
 
-fn main() {
-    Day02::run(include_str!("../../data/day02_part1_input"));
-    Day02V2::run(include_str!("../../data/day02_part1_input"));
-}
+aoc::run!(Day02, Day02V2);
 
 #[cfg(test)]
 mod tests {
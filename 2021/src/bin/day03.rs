@@ -1,28 +1,32 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 
 pub mod v2 {
+    use aoc::solution::SolutionError;
     use aoc::Solution;
 
     pub struct Day03;
 
-    impl Solution<usize, usize> for Day03 {
-        const DAY: u32 = 3;
-        const YEAR: u32 = 2021;
+    impl Solution for Day03 {
+        const DAY: u8 = 3;
+        const YEAR: u16 = 2021;
         const TITLE: &'static str = "Binary Diagnostic (V2)";
         type Input = (usize, Vec<usize>);
+        type P1 = usize;
+        type P2 = usize;
 
-        fn part1(input: &Self::Input) -> Option<usize> {
+        fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
             let (length, content) = input.to_owned();
 
             let gamma_rate: usize = (0..length)
                 .map(|shift| (Self::get_max_byte_at(shift, &content) as usize) << shift)
                 .sum();
-            let epsilon_rate = 2_usize.pow(length as u32) - 1 - gamma_rate;
+            let epsilon_rate = (usize::MAX >> (usize::BITS as usize - length)) - gamma_rate;
 
-            Some(gamma_rate * epsilon_rate)
+            Ok(gamma_rate * epsilon_rate)
         }
 
-        fn part2(input: &Self::Input) -> Option<usize> {
+        fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
             let (length, content) = input.to_owned();
 
             let (oxygen, co2) = (0..length).rev().fold(
@@ -44,19 +48,26 @@ pub mod v2 {
                 },
             );
 
-            let oxygen = oxygen[0];
-            let co2 = co2[0];
+            let oxygen = oxygen.first().ok_or(SolutionError::NoAnswer)?;
+            let co2 = co2.first().ok_or(SolutionError::NoAnswer)?;
 
-            Some(oxygen * co2)
+            Ok(oxygen * co2)
         }
 
-        fn parse(input: &str) -> Result<Self::Input, &str> {
-            let length = input.find("\n").unwrap();
+        fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+            let report = super::Day03::report(input)?;
+            let length = report[0].len();
 
-            let pool = input
-                .lines()
-                .filter_map(|line| usize::from_str_radix(&line, 2).ok())
-                .collect();
+            if length > usize::BITS as usize {
+                return Err(SolutionError::expected(input, report[0], "at most 64 bits"));
+            }
+
+            let pool = report
+                .into_iter()
+                .map(|line| {
+                    usize::from_str_radix(line, 2).map_err(|_| SolutionError::invalid(input, line))
+                })
+                .collect::<aoc::solution::Result<_>>()?;
 
             Ok((length, pool))
         }
@@ -70,31 +81,37 @@ pub mod v2 {
 }
 
 pub mod v1 {
+    use aoc::solution::SolutionError;
     use aoc::Solution;
 
     pub struct Day03;
 
-    impl Solution<usize, usize> for Day03 {
-        const DAY: u32 = 3;
-        const YEAR: u32 = 2021;
+    impl Solution for Day03 {
+        const DAY: u8 = 3;
+        const YEAR: u16 = 2021;
         const TITLE: &'static str = "Binary Diagnostic (V1)";
         type Input = Vec<String>;
+        type P1 = usize;
+        type P2 = usize;
 
-        fn part1(input: &Self::Input) -> Option<usize> {
-            let gamma_rate: String = (0..input.first().unwrap().len())
+        fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+            let width = input.first().ok_or(SolutionError::NoAnswer)?.len();
+            let gamma_rate: String = (0..width)
                 .map(|pos| {
-                    input.iter().fold(String::new(), |mut byte, row| {
-                        let bit: char = *row.chars().collect::<Vec<_>>().get(pos).unwrap();
+                    input.iter().try_fold(String::new(), |mut byte, row| {
+                        let bit = row.chars().nth(pos).ok_or(SolutionError::NoAnswer)?;
 
                         byte.push(bit);
-                        byte
+                        Ok(byte)
                     })
                 })
-                .map(|row| match row.chars().filter(|&bit| bit == '1').count() {
-                    x if x > row.len() / 2 => '1',
-                    _ => '0',
+                .map(|row| {
+                    row.map(|row| match row.chars().filter(|&bit| bit == '1').count() {
+                        x if x > row.len() / 2 => '1',
+                        _ => '0',
+                    })
                 })
-                .collect();
+                .collect::<aoc::solution::Result<_>>()?;
 
             let epsilon_rate: String = gamma_rate
                 .chars()
@@ -104,13 +121,15 @@ pub mod v1 {
                 })
                 .collect();
 
-            let gamma_rate = usize::from_str_radix(&gamma_rate, 2).unwrap();
-            let epsilon_rate = usize::from_str_radix(&epsilon_rate, 2).unwrap();
+            let gamma_rate =
+                usize::from_str_radix(&gamma_rate, 2).map_err(|_| SolutionError::NoAnswer)?;
+            let epsilon_rate =
+                usize::from_str_radix(&epsilon_rate, 2).map_err(|_| SolutionError::NoAnswer)?;
 
-            Some(gamma_rate * epsilon_rate)
+            Ok(gamma_rate * epsilon_rate)
         }
 
-        fn part2(input: &Self::Input) -> Option<usize> {
+        fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
             let oxygen = find_recur(&input, 0, |ones, zeroes| match ones.len() >= zeroes.len() {
                 true => ones,
                 _ => zeroes,
@@ -119,17 +138,18 @@ pub mod v1 {
                 true => ones,
                 _ => zeroes,
             });
-            let oxygen = usize::from_str_radix(&oxygen.unwrap(), 2).unwrap();
-            let co2 = usize::from_str_radix(&co2.unwrap(), 2).unwrap();
+            let oxygen = oxygen.ok_or(SolutionError::NoAnswer)?;
+            let co2 = co2.ok_or(SolutionError::NoAnswer)?;
+            let oxygen = usize::from_str_radix(&oxygen, 2).map_err(|_| SolutionError::NoAnswer)?;
+            let co2 = usize::from_str_radix(&co2, 2).map_err(|_| SolutionError::NoAnswer)?;
 
-            Some(oxygen * co2)
+            Ok(oxygen * co2)
         }
 
-        fn parse(input: &str) -> Result<Self::Input, &str> {
-            Ok(input
-                .trim()
-                .lines()
-                .map(|line| line.trim().to_string())
+        fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+            Ok(super::Day03::report(input)?
+                .into_iter()
+                .map(|line| line.to_string())
                 .collect())
         }
     }
@@ -138,14 +158,16 @@ pub mod v1 {
     where
         F: FnMut(Vec<String>, Vec<String>) -> Vec<String>,
     {
-        match pool.len() {
-            0 => None,
-            1 => Some(String::from(pool.first().unwrap())),
+        match &pool[..] {
+            [] => None,
+            [row] => Some(row.clone()),
+            // the rows left are all the same
+            [row, ..] if index >= row.len() => Some(row.clone()),
             _ => {
                 let (ones, zeroes): (Vec<_>, Vec<_>) = pool
                     .iter()
                     .map(|str| str.clone())
-                    .partition(|row| row.chars().collect::<Vec<_>>().get(index).unwrap() == &'1');
+                    .partition(|row| row.chars().nth(index) == Some('1'));
 
                 find_recur(&candidate(ones, zeroes), index + 1, candidate)
             }
@@ -155,26 +177,30 @@ pub mod v1 {
 
 pub mod v0_1 {
     use super::Day03 as Day03V0;
+    use aoc::solution::SolutionError;
     use aoc::Solution;
 
     pub struct Day03;
 
-    impl Solution<usize, usize> for Day03 {
-        const DAY: u32 = 3;
-        const YEAR: u32 = 2021;
+    impl Solution for Day03 {
+        const DAY: u8 = 3;
+        const YEAR: u16 = 2021;
         const TITLE: &'static str = "Binary Diagnostic (V0.1)";
         type Input = Vec<Vec<usize>>;
+        type P1 = usize;
+        type P2 = usize;
 
-        fn part1(input: &Self::Input) -> Option<usize> {
-            let flip_content: Vec<Vec<usize>> = (0..input.first().unwrap().len())
+        fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+            let width = input.first().ok_or(SolutionError::NoAnswer)?.len();
+            let flip_content: Vec<Vec<usize>> = (0..width)
                 .map(|pos| {
-                    input.iter().fold(vec![], |mut acc, row| {
-                        acc.push(row.get(pos).unwrap().clone());
+                    input.iter().try_fold(vec![], |mut acc, row| {
+                        acc.push(*row.get(pos).ok_or(SolutionError::NoAnswer)?);
 
-                        acc
+                        Ok(acc)
                     })
                 })
-                .collect();
+                .collect::<aoc::solution::Result<_>>()?;
 
             let gamma_rate: Vec<usize> = flip_content
                 .iter()
@@ -182,17 +208,15 @@ pub mod v0_1 {
                 .collect();
             let epsilon_rate: Vec<usize> = gamma_rate.iter().map(|b| 1 - b).collect();
 
-            Some(
-                Day03V0::byte_array_to_usize(&gamma_rate)
-                    * Day03V0::byte_array_to_usize(&epsilon_rate),
-            )
+            Ok(Day03V0::byte_array_to_usize(&gamma_rate)
+                * Day03V0::byte_array_to_usize(&epsilon_rate))
         }
 
-        fn part2(_input: &Self::Input) -> Option<usize> {
-            None
+        fn part2(_input: &Self::Input) -> aoc::solution::Result<usize> {
+            Err(SolutionError::NoAnswer)
         }
 
-        fn parse(input: &str) -> Result<Self::Input, &str> {
+        fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
             Day03V0::parse(input)
         }
     }
@@ -201,22 +225,24 @@ pub mod v0_1 {
 /// INITIAL submission below (converted to impl Solution trait)
 pub struct Day03;
 
-impl Solution<usize, usize> for Day03 {
-    const DAY: u32 = 3;
-    const YEAR: u32 = 2021;
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Binary Diagnostic (V0)";
     type Input = Vec<Vec<usize>>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
-        let len = input.first().unwrap().len();
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+        let len = input.first().ok_or(SolutionError::NoAnswer)?.len();
         let mut gamma_rate: Vec<usize> = vec![];
 
         //first attempt, imperative version
         for i in 0..len {
             let mut count1: usize = 0;
 
-            for j in 0..input.len() {
-                count1 += input.get(j).unwrap().get(i).unwrap();
+            for row in input {
+                count1 += row.get(i).ok_or(SolutionError::NoAnswer)?;
             }
 
             if count1 > input.len() / 2 {
@@ -231,10 +257,10 @@ impl Solution<usize, usize> for Day03 {
         let gamma_rate = Self::byte_array_to_usize(&gamma_rate);
         let epsilon_rate = Self::byte_array_to_usize(&epsilon_rate);
 
-        Some(gamma_rate * epsilon_rate)
+        Ok(gamma_rate * epsilon_rate)
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
         let oxygen = Self::find_recur(input.clone(), 0, |ones, zeroes| {
             if ones.len() >= zeroes.len() {
                 ones
@@ -242,7 +268,7 @@ impl Solution<usize, usize> for Day03 {
                 zeroes
             }
         })
-        .unwrap();
+        .ok_or(SolutionError::NoAnswer)?;
         let co2 = Self::find_recur(input.clone(), 0, |ones, zeroes| {
             if ones.len() < zeroes.len() {
                 ones
@@ -250,21 +276,19 @@ impl Solution<usize, usize> for Day03 {
                 zeroes
             }
         })
-        .unwrap();
+        .ok_or(SolutionError::NoAnswer)?;
 
         let oxygen = Self::byte_array_to_usize(&oxygen);
         let co2 = Self::byte_array_to_usize(&co2);
 
-        Some(oxygen * co2)
+        Ok(oxygen * co2)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
-            .trim()
-            .lines()
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        Ok(Self::report(input)?
+            .into_iter()
             .map(|line| {
-                line.trim()
-                    .split("")
+                line.split("")
                     .filter_map(|c| c.parse::<usize>().ok())
                     .collect()
             })
@@ -273,6 +297,36 @@ impl Solution<usize, usize> for Day03 {
 }
 
 impl Day03 {
+    /// The rows of the report, checking that they are binary numbers of the same width.
+    fn report(input: &str) -> aoc::solution::Result<Vec<&str>> {
+        let rows: Vec<&str> = input.trim().lines().map(|line| line.trim()).collect();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        if width == 0 {
+            return Err(SolutionError::expected(input, input, "binary numbers"));
+        }
+
+        for row in &rows {
+            if let Some((i, c)) = row.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+                return Err(SolutionError::expected(
+                    input,
+                    &row[i..i + c.len_utf8()],
+                    "0 or 1",
+                ));
+            }
+
+            if row.len() != width {
+                return Err(SolutionError::expected(
+                    input,
+                    row,
+                    &format!("{width} bits"),
+                ));
+            }
+        }
+
+        Ok(rows)
+    }
+
     fn byte_array_to_usize(arr: &[usize]) -> usize {
         let (count, _) = arr
             .iter()
@@ -287,9 +341,11 @@ impl Day03 {
         index: usize,
         candidate: impl Fn(Vec<Vec<usize>>, Vec<Vec<usize>>) -> Vec<Vec<usize>>,
     ) -> Option<Vec<usize>> {
-        return match arr.len() {
-            0 => None,
-            1 => Some(arr.first().unwrap().clone()),
+        return match &arr[..] {
+            [] => None,
+            [row] => Some(row.clone()),
+            // the rows left are all the same
+            [row, ..] if index >= row.len() => Some(row.clone()),
             _ => {
                 // let (ones, zeroes): (Vec<_>, Vec<_>) = arr.iter().partition(|&row| row.get(index).unwrap() == 1);
 
@@ -297,7 +353,7 @@ impl Day03 {
                 let (ones, zeroes) =
                     arr.iter()
                         .fold((vec![], vec![]), |(mut ones, mut zeroes), row| {
-                            if row.get(index).unwrap() == &1 {
+                            if row.get(index) == Some(&1) {
                                 ones.push(row.clone())
                             } else {
                                 zeroes.push(row.clone())
@@ -312,12 +368,7 @@ impl Day03 {
     }
 }
 
-fn main() {
-    Day03::run(include_str!("../../data/day03_input"));
-    v0_1::Day03::run(include_str!("../../data/day03_input"));
-    v1::Day03::run(include_str!("../../data/day03_input"));
-    v2::Day03::run(include_str!("../../data/day03_input"));
-}
+aoc::run!(Day03, v0_1::Day03, v1::Day03, v2::Day03);
 
 #[cfg(test)]
 mod tests {
@@ -345,4 +396,21 @@ mod tests {
     fn day03_v02() {
         v2::Day03::test(INPUT, Some(198), Some(230))
     }
+
+    #[test]
+    fn single_row() {
+        Day03::test("10110", Some(22 * 9), Some(22 * 22));
+        v2::Day03::test("10110", Some(22 * 9), Some(22 * 22));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let Err(SolutionError::InvalidInput(err)) = v2::Day03::parse("00100\n1110\n10110") else {
+            panic!("expected invalid input")
+        };
+
+        assert_eq!((2, 1, "1110"), (err.line, err.column, err.text.as_str()));
+        assert!(v1::Day03::parse("00100\n11210").is_err());
+        assert!(Day03::parse("\n").is_err());
+    }
 }
@@ -1,3 +1,4 @@
+use aoc::solution::{parse_in, SolutionError};
 use aoc::Solution;
 use itertools::Itertools;
 
@@ -11,41 +12,43 @@ pub enum Number {
 
 type Grid = Vec<Vec<Number>>;
 
-impl Solution<usize, usize> for Day04 {
-    const DAY: u32 = 4;
-    const YEAR: u32 = 2021;
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Giant Squid";
     type Input = Vec<usize>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
-        input.first().cloned()
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+        input.first().cloned().ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
-        input.last().cloned()
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
+        input.last().cloned().ok_or(SolutionError::NoAnswer)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        let (numbers, grids) = input.trim().split_once("\n\n").expect("Invalid Input");
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        let mut paragraphs = aoc_utils::paragraphs(input);
+        let numbers = paragraphs.next().ok_or(SolutionError::ParseError)?;
 
         let numbers = numbers
             .trim()
-            .split(",")
-            .map(|s| s.parse().expect("Invalid input"))
-            .collect();
+            .split(',')
+            .map(|s| parse_in(input, s))
+            .collect::<aoc::solution::Result<_>>()?;
 
-        let grids = grids
-            .split("\n\n")
+        let grids = paragraphs
             .map(|grid| {
                 grid.lines()
                     .map(|line| {
                         line.split_whitespace()
-                            .map(|s| Number::UnMarked(s.parse().expect("Invalid input")))
+                            .map(|s| parse_in(input, s).map(Number::UnMarked))
                             .collect()
                     })
                     .collect()
             })
-            .collect();
+            .collect::<aoc::solution::Result<_>>()?;
 
         Ok(Self::simulate(&numbers, &grids))
     }
@@ -105,9 +108,7 @@ impl Day04 {
     }
 }
 
-fn main() {
-    Day04::run(include_str!("../../data/day04_input"));
-}
+aoc::run!(Day04);
 
 #[cfg(test)]
 mod tests {
@@ -1,17 +1,20 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use aoc::solution::{parse_in, SolutionError};
 use aoc::Solution;
 
 pub struct Day05;
 
-impl Solution<usize, usize> for Day05 {
-    const DAY: u32 = 5;
-    const YEAR: u32 = 2021;
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Hydrothermal Venture";
     type Input = Vec<((i32, i32), (i32, i32))>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let canvas = input.iter().fold(HashMap::new(), |mut canvas, line| {
             match line {
                 (a, b) if a.0 == b.0 || a.1 == b.1 => Self::draw_line(&mut canvas, &a, &b),
@@ -21,34 +24,39 @@ impl Solution<usize, usize> for Day05 {
             canvas
         });
 
-        Some(canvas.values().filter(|&&value| value > 1).count())
+        Ok(canvas.values().filter(|&&value| value > 1).count())
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
         let canvas = input.iter().fold(HashMap::new(), |mut canvas, (a, b)| {
             Self::draw_line(&mut canvas, &a, &b);
 
             canvas
         });
 
-        Some(canvas.values().filter(|&&value| value > 1).count())
+        Ok(canvas.values().filter(|&&value| value > 1).count())
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        let point = |text: &str| -> aoc::solution::Result<_> {
+            let (x, y) = text
+                .split_once(',')
+                .ok_or_else(|| SolutionError::invalid(input, text))?;
+
+            Ok((parse_in(input, x)?, parse_in(input, y)?))
+        };
+
+        input
             .trim()
             .lines()
             .map(|line| {
-                let (a, b) = line.split_once(" -> ").expect("Invalid input");
-                let (xa, ya) = a.split_once(",").expect("Invalid input");
-                let (xb, yb) = b.split_once(",").expect("Invalid input");
-
-                (
-                    (xa.parse().unwrap(), ya.parse().unwrap()),
-                    (xb.parse().unwrap(), yb.parse().unwrap()),
-                )
+                let (a, b) = line
+                    .split_once(" -> ")
+                    .ok_or_else(|| SolutionError::invalid(input, line))?;
+
+                Ok((point(a)?, point(b)?))
             })
-            .collect())
+            .collect()
     }
 }
 
@@ -77,9 +85,7 @@ impl Day05 {
     }
 }
 
-fn main() {
-    Day05::run(include_str!("../../data/day05_input"));
-}
+aoc::run!(Day05);
 
 #[cfg(test)]
 mod tests {
@@ -93,4 +99,13 @@ mod tests {
             Some(12),
         );
     }
+
+    #[test]
+    fn invalid_input() {
+        let Err(SolutionError::InvalidInput(err)) = Day05::parse("0,9 -> 5,9\n8,0 -> O,8\n") else {
+            panic!("expected invalid input")
+        };
+
+        assert_eq!((2, 8, "O"), (err.line, err.column, err.text.as_str()));
+    }
 }
@@ -1,30 +1,32 @@
+use aoc::solution::{parse_in, SolutionError};
 use aoc::Solution;
 
 pub struct Day06;
 
-impl Solution<usize, usize> for Day06 {
-    const DAY: u32 = 6;
-    const YEAR: u32 = 2021;
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Lanternfish";
     type Input = [usize; 9];
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
-        Some(Self::making_babies(&input[..], 80))
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Ok(Self::making_babies(&input[..], 80))
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
-        Some(Self::making_babies(&input[..], 256))
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Ok(Self::making_babies(&input[..], 256))
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
-            .trim()
-            .split(",")
-            .filter_map(|i| i.parse::<usize>().ok())
-            .fold([0; 9], |mut map, i| {
-                map[i] += 1;
-                map
-            }))
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        input.trim().split(',').try_fold([0; 9], |mut map, timer| {
+            let i: usize = parse_in(input, timer)?;
+
+            *map.get_mut(i)
+                .ok_or_else(|| SolutionError::invalid(input, timer))? += 1;
+            Ok(map)
+        })
     }
 }
 
@@ -46,29 +48,31 @@ impl Day06 {
 
 pub struct Day06V0;
 
-impl Solution<usize, usize> for Day06V0 {
-    const DAY: u32 = 6;
-    const YEAR: u32 = 2021;
+impl Solution for Day06V0 {
+    const DAY: u8 = 6;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Lanternfish (naive version)";
     type Input = Vec<usize>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
-        Some(Self::get_population(&input, 80).len())
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Ok(Self::get_population(&input, 80).len())
     }
 
-    fn part2(_input: &Self::Input) -> Option<usize> {
+    fn part2(_input: &Self::Input) -> aoc::solution::Result<usize> {
         None
-
-        // This would take Ages :D
-        // Some(Self::get_population(&input, 256).len())
+            // This would take Ages :D
+            // Some(Self::get_population(&input, 256).len())
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        input
             .trim()
-            .split(",")
-            .map(|i| i.parse::<usize>().unwrap())
-            .collect())
+            .split(',')
+            .map(|timer| parse_in(input, timer))
+            .collect()
     }
 }
 
@@ -95,10 +99,7 @@ impl Day06V0 {
     }
 }
 
-fn main() {
-    Day06V0::run(include_str!("../../data/day06_input"));
-    Day06::run(include_str!("../../data/day06_input"));
-}
+aoc::run!(Day06V0, Day06);
 
 #[cfg(test)]
 mod tests {
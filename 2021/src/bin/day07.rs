@@ -1,16 +1,19 @@
+use aoc::solution::parse_in;
 use aoc::Solution;
 use itertools::Itertools;
 use std::collections::HashMap;
 
 pub struct Day07;
 
-impl Solution<usize, usize> for Day07 {
-    const DAY: u32 = 7;
-    const YEAR: u32 = 2021;
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "The Treachery of Whales";
     type Input = Vec<i32>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let median_index = (input.len() + 1) / 2;
         let median_pos = input.get(median_index).unwrap();
 
@@ -19,10 +22,10 @@ impl Solution<usize, usize> for Day07 {
             .map(|pos| (pos - median_pos).abs())
             .sum::<i32>();
 
-        Some(fuel_cost as usize)
+        Ok(fuel_cost as usize)
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
         let crabs: HashMap<_, _> = input.into_iter().fold(HashMap::new(), |mut pool, &pos| {
             *pool.entry(pos).or_insert(0) += 1;
             pool
@@ -45,22 +48,21 @@ impl Solution<usize, usize> for Day07 {
             .min_by(|a, b| a.1.cmp(&b.1))
             .unwrap();
 
-        Some(fuel_cost as usize)
+        Ok(fuel_cost as usize)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        let crabs: Vec<i32> = input
             .trim()
-            .split(",")
-            .map(|input| input.parse::<i32>().unwrap())
-            .sorted()
-            .collect::<Vec<_>>())
+            .split(',')
+            .map(|crab| parse_in(input, crab))
+            .collect::<aoc::solution::Result<_>>()?;
+
+        Ok(crabs.into_iter().sorted().collect())
     }
 }
 
-fn main() {
-    Day07::run(include_str!("../../data/day07_input"));
-}
+aoc::run!(Day07);
 
 #[cfg(test)]
 mod tests {
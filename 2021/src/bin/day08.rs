@@ -1,15 +1,18 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use itertools::Itertools;
 
 pub struct Day08;
 
-impl Solution<usize, usize> for Day08 {
-    const DAY: u32 = 8;
-    const YEAR: u32 = 2021;
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Seven Segment Search";
     type Input = Vec<(Vec<String>, Vec<String>)>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let count_1_4_7_or_8: usize = input
             .iter()
             .map(|(_, output)| {
@@ -20,35 +23,34 @@ impl Solution<usize, usize> for Day08 {
             })
             .sum();
 
-        Some(count_1_4_7_or_8)
+        Ok(count_1_4_7_or_8)
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
-        Some(
-            input
-                .iter()
-                .map(|(unique, output)| {
-                    let known_wires = Self::get_known_wires(unique);
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Ok(input
+            .iter()
+            .map(|(unique, output)| {
+                let known_wires = Self::get_known_wires(unique);
 
-                    output.iter().fold(0_usize, |value, wires| {
-                        (value * 10) + Self::get_value_from_known_wires(&wires, &known_wires)
-                    })
+                output.iter().fold(0_usize, |value, wires| {
+                    (value * 10) + Self::get_value_from_known_wires(&wires, &known_wires)
                 })
-                .sum::<usize>(),
-        )
+            })
+            .sum::<usize>())
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        input
             .lines()
             .map(|line| {
                 let entries: Vec<_> = line.split_whitespace().map(|l| l.to_string()).collect();
-                let input = entries[0..10].to_owned();
-                let output = entries[11..15].to_owned();
 
-                (input, output)
+                match entries.len() {
+                    15 => Ok((entries[0..10].to_owned(), entries[11..15].to_owned())),
+                    _ => Err(SolutionError::invalid(input, line)),
+                }
             })
-            .collect())
+            .collect()
     }
 }
 
@@ -102,9 +104,7 @@ impl Day08 {
     }
 }
 
-fn main() {
-    Day08::run(include_str!("../../data/day08_input"));
-}
+aoc::run!(Day08);
 
 #[cfg(test)]
 mod tests {
@@ -4,25 +4,25 @@ use itertools::Itertools;
 
 pub struct Day09;
 
-impl Solution<usize, usize> for Day09 {
-    const DAY: u32 = 9;
-    const YEAR: u32 = 2021;
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Smoke Basin";
     type Input = Vec<Vec<usize>>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let low_points = Self::find_low_points(&input);
 
-        Some(
-            low_points
-                .iter()
-                .map(|(_, _, height)| height)
-                .sum::<usize>()
-                + low_points.len(),
-        )
+        Ok(low_points
+            .iter()
+            .map(|(_, _, height)| height)
+            .sum::<usize>()
+            + low_points.len())
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
         let low_points = Self::find_low_points(&input);
 
         let basins = low_points
@@ -43,10 +43,10 @@ impl Solution<usize, usize> for Day09 {
             .rev()
             .collect::<Vec<_>>();
 
-        Some(basin_sizes.iter().take(3).product())
+        Ok(basin_sizes.iter().take(3).product())
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
         Ok(input
             .lines()
             .map(|line| {
@@ -98,9 +98,7 @@ impl Day09 {
     }
 }
 
-fn main() {
-    Day09::run(include_str!("../../data/day09_input"));
-}
+aoc::run!(Day09);
 
 #[cfg(test)]
 mod tests {
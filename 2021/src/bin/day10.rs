@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use itertools::Itertools;
 use State::{Complete, Corrupted, Incomplete};
@@ -10,13 +11,15 @@ pub enum State {
     Incomplete(Vec<char>),
 }
 
-impl Solution<usize, usize> for Day10 {
-    const DAY: u32 = 10;
-    const YEAR: u32 = 2021;
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Syntax Scoring";
     type Input = Vec<State>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let all_corrupted = input
             .iter()
             .filter_map(|result| match result {
@@ -25,21 +28,19 @@ impl Solution<usize, usize> for Day10 {
             })
             .collect::<Vec<_>>();
 
-        Some(
-            all_corrupted
-                .iter()
-                .map(|&c| match c {
-                    ')' => 3,
-                    ']' => 57,
-                    '}' => 1197,
-                    '>' => 25137,
-                    _ => panic!(),
-                })
-                .sum::<usize>(),
-        )
+        Ok(all_corrupted
+            .iter()
+            .map(|&c| match c {
+                ')' => 3,
+                ']' => 57,
+                '}' => 1197,
+                '>' => 25137,
+                _ => panic!(),
+            })
+            .sum::<usize>())
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
         let all_incomplete = input
             .iter()
             .filter_map(|result| match result {
@@ -65,43 +66,43 @@ impl Solution<usize, usize> for Day10 {
             .sorted()
             .collect();
 
-        score.get(score.len() / 2).cloned()
+        score
+            .get(score.len() / 2)
+            .cloned()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        input
             .lines()
-            .map(|s| String::from(s.trim()))
+            .map(|line| line.trim())
             .map(|line| {
                 let mut stack = vec![];
                 let mut corrupted = vec![];
 
-                for c in line.chars() {
+                for (i, c) in line.char_indices() {
                     match c {
                         '(' | '[' | '{' | '<' => stack.push(c),
                         ')' if stack.pop() == Some('(') => {}
                         ']' if stack.pop() == Some('[') => {}
                         '}' if stack.pop() == Some('{') => {}
                         '>' if stack.pop() == Some('<') => {}
-                        c => {
-                            corrupted.push(c);
-                        }
+                        ')' | ']' | '}' | '>' => corrupted.push(c),
+                        _ => return Err(SolutionError::invalid(input, &line[i..i + c.len_utf8()])),
                     }
                 }
 
-                match (corrupted.len(), stack.len()) {
+                Ok(match (corrupted.len(), stack.len()) {
                     (n, _) if n > 0 => Corrupted(corrupted),
                     (_, n) if n > 0 => Incomplete(stack),
                     _ => Complete,
-                }
+                })
             })
-            .collect())
+            .collect()
     }
 }
 
-fn main() {
-    Day10::run(include_str!("../../data/day10_input"));
-}
+aoc::run!(Day10);
 
 #[cfg(test)]
 mod tests {
@@ -112,4 +113,13 @@ mod tests {
     fn day10() {
         Day10::test(INPUT, Some(26397), Some(288957));
     }
+
+    #[test]
+    fn invalid_input() {
+        let Err(SolutionError::InvalidInput(err)) = Day10::parse("[]\n(a)") else {
+            panic!("expected invalid input")
+        };
+
+        assert_eq!((2, 2, "a"), (err.line, err.column, err.text.as_str()));
+    }
 }
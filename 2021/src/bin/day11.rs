@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use std::collections::VecDeque;
 
@@ -11,9 +12,9 @@ pub struct Grid {
 }
 
 impl Grid {
-    fn from_str(src: &str) -> Result<Self, &str> {
+    fn from_str(src: &str) -> aoc::solution::Result<Self> {
         let src = src.trim();
-        let width = src.find("\n").ok_or("Invalid input")?;
+        let width = src.find("\n").ok_or(SolutionError::ParseError)?;
         let octopuses: Vec<_> = src
             .lines()
             .flat_map(|line| line.chars().filter_map(|c| c.to_digit(10)))
@@ -92,23 +93,25 @@ impl Grid {
     }
 }
 
-impl Solution<usize, usize> for Day11 {
-    const DAY: u32 = 11;
-    const YEAR: u32 = 2021;
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Dumbo Octopus";
     type Input = Grid;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let (count, _) = (0..100).fold((0, input.clone()), |(mut count, mut grid), _| {
             count += grid.flash();
 
             (count, grid)
         });
 
-        Some(count)
+        Ok(count)
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
         let mut grid = input.to_owned();
         let mut step = 1;
 
@@ -116,17 +119,15 @@ impl Solution<usize, usize> for Day11 {
             step += 1
         }
 
-        Some(step)
+        Ok(step)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
         Grid::from_str(&input)
     }
 }
 
-fn main() {
-    Day11::run(include_str!("../../data/day11_input"));
-}
+aoc::run!(Day11);
 
 #[cfg(test)]
 mod tests {
@@ -3,25 +3,27 @@ use std::collections::HashMap;
 
 pub struct Day12;
 
-impl Solution<usize, usize> for Day12 {
-    const DAY: u32 = 12;
-    const YEAR: u32 = 2021;
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Passage Pathing";
     type Input = HashMap<String, Vec<String>>;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let solves = Self::backtrack(input, "start", "start", "end", &mut Vec::new(), false);
 
-        Some(solves.len())
+        Ok(solves.len())
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
         let solves = Self::backtrack(input, "start", "start", "end", &mut Vec::new(), true);
 
-        Some(solves.len())
+        Ok(solves.len())
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
         Ok(input
             .trim()
             .lines()
@@ -89,9 +91,7 @@ impl Day12 {
     }
 }
 
-fn main() {
-    Day12::run(include_str!("../../data/day12_input"));
-}
+aoc::run!(Day12);
 
 #[cfg(test)]
 mod tests {
@@ -1,79 +1,75 @@
+use aoc::solution::{parse_in, SolutionError};
 use aoc::Solution;
-use itertools::Itertools;
-use std::collections::HashSet;
+use aoc_utils::collections::SparseGrid;
 
 pub struct Day13;
-pub type DotSet = HashSet<(i32, i32)>;
+pub type DotSet = SparseGrid<()>;
 
-impl Solution<usize, String> for Day13 {
-    const DAY: u32 = 13;
-    const YEAR: u32 = 2021;
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Transparent Origami";
-    type Input = (DotSet, Vec<(i32, i32)>);
+    type Input = (DotSet, Vec<(i64, i64)>);
+    type P1 = usize;
+    type P2 = String;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
         let (dots, instructions) = input;
 
-        Some(
-            instructions
-                .iter()
-                .take(1)
-                .fold(dots.clone(), Self::fold)
-                .len(),
-        )
+        Ok(instructions
+            .iter()
+            .take(1)
+            .fold(dots.clone(), Self::fold)
+            .len())
     }
 
-    fn part2(input: &Self::Input) -> Option<String> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<String> {
         let (dots, instructions) = input;
 
         let code = instructions.iter().fold(dots.clone(), Self::fold);
-        let width = code.iter().map(|(x, _)| x).max().unwrap() + 1;
-        let height = code.iter().map(|(_, y)| y).max().unwrap() + 1;
-
-        let sheet = (0..height)
-            .map(|y| {
-                (0..width).fold(String::new(), |acc, x| {
-                    acc + code.get(&(x, y)).and(Some("▓")).or(Some(" ")).unwrap()
-                })
-            })
-            .join("\n");
+        let sheet = code.render(|_, dot| match dot {
+            Some(_) => '▓',
+            None => ' ',
+        });
 
-        Some("\n".to_string() + &sheet)
+        Ok("\n".to_string() + &sheet)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        let (dots, instructions) = input.split_once("\n\n").expect("Invalid input");
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        let (sheet, instructions) = input.split_once("\n\n").ok_or(SolutionError::ParseError)?;
 
-        let dots: DotSet = dots
+        let dots: DotSet = sheet
             .lines()
-            .map(|coord| match coord.split_once(",") {
-                Some((x, y)) => (x.parse::<i32>().unwrap(), y.parse::<_>().unwrap()),
-                _ => panic!(),
+            .map(|coord| match coord.split_once(',') {
+                Some((x, y)) => Ok((parse_in::<i64>(input, x)?, parse_in(input, y)?)),
+                _ => Err(SolutionError::invalid(input, coord)),
             })
-            .collect();
+            .collect::<aoc::solution::Result<_>>()?;
+
+        if dots.is_empty() {
+            return Err(SolutionError::expected(input, sheet, "at least one dot"));
+        }
 
         let instructions: Vec<_> = instructions
             .lines()
-            .map(|line| match line.split_once("=") {
-                Some(("fold along y", val)) => (0, val.parse::<i32>().unwrap()),
-                Some(("fold along x", val)) => (val.parse::<i32>().unwrap(), 0),
-                _ => panic!(),
+            .map(|line| match line.split_once('=') {
+                Some(("fold along y", val)) => Ok((0, parse_in(input, val)?)),
+                Some(("fold along x", val)) => Ok((parse_in(input, val)?, 0)),
+                _ => Err(SolutionError::invalid(input, line)),
             })
-            .collect();
+            .collect::<aoc::solution::Result<_>>()?;
         Ok((dots, instructions))
     }
 }
 
 impl Day13 {
-    fn fold(dots: DotSet, pivot: &(i32, i32)) -> DotSet {
-        let sheet_height = dots.iter().map(|(_, y)| y).max().unwrap();
+    fn fold(dots: DotSet, pivot: &(i64, i64)) -> DotSet {
+        let (sheet_width, sheet_height) = dots.bounds().expect("parse rejects an empty sheet").max;
         let new_height = pivot.1.max(sheet_height - pivot.1);
-
-        let sheet_width = dots.iter().map(|(x, _)| x).max().unwrap();
         let new_width = pivot.0.max(sheet_width - pivot.0);
 
         dots.into_iter()
-            .map(|(x, y)| {
+            .map(|((x, y), _)| {
                 (
                     match x {
                         coord if coord > new_width => new_width - (coord - new_width).abs(),
@@ -89,9 +85,7 @@ impl Day13 {
     }
 }
 
-fn main() {
-    Day13::run(include_str!("../../data/day13_input"));
-}
+aoc::run!(Day13);
 
 #[cfg(test)]
 mod tests {
@@ -106,4 +100,14 @@ mod tests {
             Some("\n▓▓▓▓▓\n▓   ▓\n▓   ▓\n▓   ▓\n▓▓▓▓▓".to_owned()),
         );
     }
+
+    #[test]
+    fn day13_no_dots() {
+        let Err(SolutionError::InvalidInput(err)) = Day13::parse("\n\nfold along x=5") else {
+            panic!("expected invalid input")
+        };
+
+        assert_eq!((1, 1), (err.line, err.column));
+        assert_eq!(Some("at least one dot"), err.expected.as_deref());
+    }
 }
@@ -1,39 +1,46 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use itertools::Itertools;
 use std::collections::HashMap;
 
 pub struct Day14;
 
-impl Solution<usize, usize> for Day14 {
-    const DAY: u32 = 14;
-    const YEAR: u32 = 2021;
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Extended Polymerization";
     type Input = (Vec<(char, char)>, HashMap<(char, char), char>);
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
-        Self::grow_polymer(&input, 10)
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Self::grow_polymer(&input, 10).ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
-        Self::grow_polymer(&input, 40)
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Self::grow_polymer(&input, 40).ok_or(SolutionError::NoAnswer)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        let (template, rules) = input.trim().split_once("\n\n").expect("Invalid input");
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        let (template, rules) = input
+            .trim()
+            .split_once("\n\n")
+            .ok_or(SolutionError::ParseError)?;
 
         Ok((
             template.chars().tuple_windows().collect(),
             rules
                 .lines()
                 .map(|line| {
-                    let (pattern, insert) = line.split_once(" -> ").expect("Invalid input");
+                    let invalid = || SolutionError::invalid(input, line);
+                    let (pattern, insert) = line.split_once(" -> ").ok_or_else(invalid)?;
 
-                    (
-                        pattern.chars().collect_tuple().unwrap(),
-                        insert.as_bytes()[0] as char,
-                    )
+                    Ok((
+                        pattern.chars().collect_tuple().ok_or_else(invalid)?,
+                        insert.chars().next().ok_or_else(invalid)?,
+                    ))
                 })
-                .collect(),
+                .collect::<aoc::solution::Result<_>>()?,
         ))
     }
 }
@@ -106,9 +113,7 @@ impl Day14 {
     }
 }
 
-fn main() {
-    Day14::run(include_str!("../../data/day14_input"));
-}
+aoc::run!(Day14);
 
 #[cfg(test)]
 mod tests {
@@ -1,30 +1,60 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 
-use aoc::utils;
 use aoc_utils::dijkstra::{self, Boundaries, Coord};
+use aoc_utils::neighbors;
+use aoc_utils::pathfinding::{a_star, Graph};
 
 pub struct Day15;
 
-impl Solution<i32, i32> for Day15 {
-    const DAY: u32 = 15;
-    const YEAR: u32 = 2021;
+struct Cavern {
+    width: usize,
+    height: usize,
+}
+
+impl Graph<(usize, usize)> for Cavern {
+    fn start(&self) -> Option<(usize, usize)> {
+        Some((0, 0))
+    }
+
+    fn adjacent(&self, &(x, y): &(usize, usize)) -> Option<Vec<(usize, usize)>> {
+        Some(neighbors(self.width, self.height, x, y))
+    }
+
+    fn is_target(&self, node: &(usize, usize)) -> bool {
+        node == &(self.width - 1, self.height - 1)
+    }
+
+    fn estimated_size(&self) -> usize {
+        self.width * self.height
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Chiton";
     type Input = (Vec<Vec<i32>>, usize, usize);
+    type P1 = i32;
+    type P2 = i32;
 
-    fn part1(input: &Self::Input) -> Option<i32> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<i32> {
         let (map, width, height) = input;
+        let target = (*width - 1, *height - 1);
 
-        utils::a_star::solve(
-            &(0, 0),
-            &(*width - 1, *height - 1),
-            &(*width, *height),
+        a_star::solve(
+            &Cavern {
+                width: *width,
+                height: *height,
+            },
             |_, &(x, y)| Some(map[y][x]),
-            Self::manhattan_dist,
+            |node| Self::manhattan_dist(&target, node),
         )
-        .map(|(_, cost)| cost)
+        .map(|(cost, _)| cost)
+        .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<i32> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<i32> {
         let (map, width, height) = input;
 
         let proj_width = *width * 5;
@@ -53,16 +83,25 @@ impl Solution<i32, i32> for Day15 {
             |current| current == &target,
         )
         .map(|(_, total_cost)| total_cost)
+        .ok_or(SolutionError::NoAnswer)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
         let map: Vec<Vec<i32>> = input
             .trim()
             .lines()
-            .map(|line| line.chars().map(|c| (c as u8 - b'0') as i32).collect())
-            .collect();
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, c)| {
+                        c.to_digit(10).map(|risk| risk as i32).ok_or_else(|| {
+                            SolutionError::invalid(input, &line[i..i + c.len_utf8()])
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<aoc::solution::Result<_>>()?;
         let height = map.len();
-        let width = input.find('\n').expect("Invalid input");
+        let width = input.find('\n').ok_or(SolutionError::ParseError)?;
 
         Ok((map, width, height))
     }
@@ -77,9 +116,7 @@ impl Day15 {
     }
 }
 
-fn main() {
-    Day15::run(include_str!("../../data/day15_input"));
-}
+aoc::run!(Day15);
 
 #[cfg(test)]
 mod tests {
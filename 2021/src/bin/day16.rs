@@ -1,5 +1,5 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
-use itertools::Itertools;
 
 pub struct Day16;
 
@@ -18,101 +18,112 @@ pub enum Packet {
     },
 }
 
+/// The bits of `packet` in `range`, when they are all there.
+fn bits(packet: &str, range: std::ops::Range<usize>) -> aoc::solution::Result<&str> {
+    packet.get(range).ok_or(SolutionError::ParseError)
+}
+
+fn number(bits: &str) -> aoc::solution::Result<usize> {
+    usize::from_str_radix(bits, 2).map_err(|_| SolutionError::ParseError)
+}
+
 impl Packet {
-    pub fn from_str(packet: &str) -> Self {
+    pub fn from_str(packet: &str) -> aoc::solution::Result<Self> {
         const HEADER_SIZE: usize = 6;
-        let version = &packet[0..3];
-        let type_id = &packet[3..HEADER_SIZE];
-        let body = &packet[HEADER_SIZE..];
-        let version = usize::from_str_radix(version, 2).unwrap();
-        let type_id = usize::from_str_radix(type_id, 2).unwrap();
+        let version = number(bits(packet, 0..3)?)?;
+        let type_id = number(bits(packet, 3..HEADER_SIZE)?)?;
+        let body = bits(packet, HEADER_SIZE..packet.len())?;
 
         match type_id {
             4 => {
                 let mut pointer = 0usize;
                 let mut sub_packet = String::new();
 
-                while let Some('1') = &body[pointer..(pointer + 1)].chars().next() {
-                    sub_packet += &body[(pointer + 1)..(pointer + 5)];
+                while bits(body, pointer..(pointer + 1))? == "1" {
+                    sub_packet += bits(body, (pointer + 1)..(pointer + 5))?;
                     pointer += 5;
                 }
-                sub_packet += &body[(pointer + 1)..(pointer + 5)];
+                sub_packet += bits(body, (pointer + 1)..(pointer + 5))?;
 
-                Packet::Literal {
+                Ok(Packet::Literal {
                     version,
-                    value: usize::from_str_radix(&sub_packet, 2).unwrap(),
-                    raw: (&packet[0..(HEADER_SIZE + pointer + 5)]).to_string(),
-                }
+                    value: number(&sub_packet)?,
+                    raw: packet[0..(HEADER_SIZE + pointer + 5)].to_string(),
+                })
             }
-            operation => match &body[0..1] {
+            operation => match bits(body, 0..1)? {
                 "0" => {
                     let start = 1;
                     let cursor = start + 15;
-                    let sub_packets_length =
-                        usize::from_str_radix(&body[start..(cursor)], 2).unwrap();
+                    let sub_packets_length = number(bits(body, start..cursor)?)?;
+                    let sub_body = bits(body, cursor..(cursor + sub_packets_length))?;
 
                     let mut size = 0;
                     let mut sub_packets = Vec::new();
 
                     while size < sub_packets_length {
-                        let packet =
-                            Self::from_str(&body[(cursor + size)..(cursor + sub_packets_length)]);
+                        let packet = Self::from_str(&sub_body[size..])?;
 
                         size += packet.size();
 
                         sub_packets.push(packet);
                     }
 
-                    Packet::Operator {
+                    Ok(Packet::Operator {
                         version,
                         operation,
-                        raw: (&packet[0..(HEADER_SIZE + cursor + sub_packets_length)]).to_string(),
+                        raw: packet[0..(HEADER_SIZE + cursor + sub_packets_length)].to_string(),
                         packets: sub_packets,
-                    }
+                    })
                 }
-                "1" => {
+                _ => {
                     let start = 1;
                     let cursor = start + 11;
-                    let sub_packet_count = usize::from_str_radix(&body[start..cursor], 2).unwrap();
+                    let sub_packet_count = number(bits(body, start..cursor)?)?;
 
                     let mut sub_packets = Vec::new();
                     let mut size = 0;
 
                     for _ in 0..sub_packet_count {
-                        let packet = Self::from_str(&body[(cursor + size)..]);
+                        let packet = Self::from_str(bits(body, (cursor + size)..body.len())?)?;
 
                         size += packet.size();
 
                         sub_packets.push(packet);
                     }
 
-                    Packet::Operator {
+                    Ok(Packet::Operator {
                         version,
                         operation,
-                        raw: (&packet[0..(HEADER_SIZE + cursor + size)]).to_string(),
+                        raw: packet[0..(HEADER_SIZE + cursor + size)].to_string(),
                         packets: sub_packets,
-                    }
+                    })
                 }
-                _ => unreachable!(),
             },
         }
     }
 
-    pub fn decode(&self) -> usize {
+    /// The value of the packet, `None` when an operator is missing operands.
+    pub fn decode(&self) -> Option<usize> {
         match self {
-            Self::Literal { value, .. } => *value,
+            Self::Literal { value, .. } => Some(*value),
             Self::Operator {
                 operation, packets, ..
-            } => match operation {
-                0 => packets.iter().map(Self::decode).sum(),
-                1 => packets.iter().map(Self::decode).product(),
-                2 => packets.iter().map(Self::decode).min().unwrap(),
-                3 => packets.iter().map(Self::decode).max().unwrap(),
-                5 => (packets[0].decode() > packets[1].decode()) as usize,
-                6 => (packets[0].decode() < packets[1].decode()) as usize,
-                7 => (packets[0].decode() == packets[1].decode()) as usize,
-                _ => 0,
-            },
+            } => {
+                let values: Vec<usize> = packets.iter().map(Self::decode).collect::<Option<_>>()?;
+
+                match (operation, &values[..]) {
+                    (0, _) => Some(values.iter().sum()),
+                    (1, _) => Some(values.iter().product()),
+                    (2, _) => values.iter().min().copied(),
+                    (3, _) => values.iter().max().copied(),
+                    (5, [a, b, ..]) => Some((a > b) as usize),
+                    (6, [a, b, ..]) => Some((a < b) as usize),
+                    (7, [a, b, ..]) => Some((a == b) as usize),
+                    (5..=7, _) => None,
+                    _ => Some(0),
+                }
+            }
         }
     }
 
@@ -133,36 +144,44 @@ impl Packet {
     }
 }
 
-impl Solution<usize, usize> for Day16 {
-    const DAY: u32 = 16;
-    const YEAR: u32 = 2021;
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const YEAR: u16 = 2021;
     const TITLE: &'static str = "Packet Decoder";
-    type Input = String;
+    type Input = Packet;
+    type P1 = usize;
+    type P2 = usize;
 
-    fn part1(input: &Self::Input) -> Option<usize> {
-        let packet = Packet::from_str(input);
-
-        Some(packet.version_sum())
+    fn part1(input: &Self::Input) -> aoc::solution::Result<usize> {
+        Ok(input.version_sum())
     }
 
-    fn part2(input: &Self::Input) -> Option<usize> {
-        let packet = Packet::from_str(input);
-
-        Some(packet.decode())
+    fn part2(input: &Self::Input) -> aoc::solution::Result<usize> {
+        input.decode().ok_or(SolutionError::NoAnswer)
     }
 
-    fn parse(input: &str) -> Result<Self::Input, &str> {
-        Ok(input
-            .trim()
-            .chars()
-            .map(|c| format!("{:04b}", c.to_digit(16).unwrap_or(0)))
-            .join(""))
+    fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
+        let transmission = input.trim();
+        let bits: String = transmission
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(16)
+                    .map(|digit| format!("{:04b}", digit))
+                    .ok_or_else(|| {
+                        SolutionError::expected(
+                            input,
+                            &transmission[i..i + c.len_utf8()],
+                            "hexadecimal digit",
+                        )
+                    })
+            })
+            .collect::<aoc::solution::Result<_>>()?;
+
+        Packet::from_str(&bits).map_err(|err| err.within(input, transmission))
     }
 }
 
-fn main() {
-    Day16::run(include_str!("../../data/day16_input"));
-}
+aoc::run!(Day16);
 
 #[cfg(test)]
 mod day16 {
@@ -208,4 +227,14 @@ mod day16 {
     fn part_2_case_6() {
         Day16::test("9C0141080250320F1802104A08", None, Some(1));
     }
+    #[test]
+    fn invalid_input() {
+        let Err(SolutionError::InvalidInput(err)) = Day16::parse("8A004G801A8002F478") else {
+            panic!("expected invalid input")
+        };
+
+        assert_eq!((1, 6, "G"), (err.line, err.column, err.text.as_str()));
+        assert!(Day16::parse("8A004A801A8002F4").is_err());
+        assert!(Day16::parse("").is_err());
+    }
 }
@@ -1,4 +1,3 @@
-mod solution;
-pub mod utils;
+//! Every day of the year, registered for the `aoc` binary, see `build.rs`.
 
-pub use solution::Solution;
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
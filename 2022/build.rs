@@ -0,0 +1,3 @@
+fn main() {
+    aoc::build::register_bins();
+}
@@ -1,17 +1,18 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 
 struct Day01;
 
 impl Solution for Day01 {
     const TITLE: &'static str = "Calorie Counting";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 1;
     type Input = Vec<usize>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        let list = input
-            .split("\r\n\r\n")
+        let list = aoc_utils::paragraphs(input)
             .map(|elf| {
                 elf.lines()
                     .filter_map(|line| line.parse::<usize>().ok())
@@ -22,22 +23,20 @@ impl Solution for Day01 {
         Ok(list)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        input.iter().max().cloned()
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        input.iter().max().cloned().ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let mut input: Vec<usize> = input.clone();
 
         input.sort_by(|a, b| b.cmp(a));
 
-        Some(input.iter().take(3).sum())
+        Ok(input.iter().take(3).sum())
     }
 }
 
-fn main() {
-    aoc::solution!(Day01)
-}
+aoc::run!(Day01);
 #[cfg(test)]
 mod tests {
     use crate::Day01 as day_01;
@@ -43,6 +43,7 @@ impl RPS {
 
 impl Solution for Day02 {
     const TITLE: &'static str = "Rock Paper Scissors";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 2;
     type Input = Vec<(String, String)>;
     type P1 = usize;
@@ -58,49 +59,43 @@ impl Solution for Day02 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(
-            input
-                .iter()
-                .map(|(elf, me)| {
-                    let elf = RPS::from(&elf[..]);
-                    let me = RPS::from(&me[..]);
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(input
+            .iter()
+            .map(|(elf, me)| {
+                let elf = RPS::from(&elf[..]);
+                let me = RPS::from(&me[..]);
 
-                    me.battle(&elf) + me.points()
-                })
-                .sum(),
-        )
+                me.battle(&elf) + me.points()
+            })
+            .sum())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        Some(
-            input
-                .iter()
-                .map(|(elf, me)| {
-                    let elf = RPS::from(&elf[..]);
-                    let me = match (&elf, &me[..]) {
-                        (RPS::Rock, "X") => RPS::Scissors,
-                        (RPS::Rock, "Y") => RPS::Rock,
-                        (RPS::Rock, "Z") => RPS::Paper,
-                        (RPS::Paper, "X") => RPS::Rock,
-                        (RPS::Paper, "Y") => RPS::Paper,
-                        (RPS::Paper, "Z") => RPS::Scissors,
-                        (RPS::Scissors, "X") => RPS::Paper,
-                        (RPS::Scissors, "Y") => RPS::Scissors,
-                        (RPS::Scissors, "Z") => RPS::Rock,
-                        _ => unreachable!(),
-                    };
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Ok(input
+            .iter()
+            .map(|(elf, me)| {
+                let elf = RPS::from(&elf[..]);
+                let me = match (&elf, &me[..]) {
+                    (RPS::Rock, "X") => RPS::Scissors,
+                    (RPS::Rock, "Y") => RPS::Rock,
+                    (RPS::Rock, "Z") => RPS::Paper,
+                    (RPS::Paper, "X") => RPS::Rock,
+                    (RPS::Paper, "Y") => RPS::Paper,
+                    (RPS::Paper, "Z") => RPS::Scissors,
+                    (RPS::Scissors, "X") => RPS::Paper,
+                    (RPS::Scissors, "Y") => RPS::Scissors,
+                    (RPS::Scissors, "Z") => RPS::Rock,
+                    _ => unreachable!(),
+                };
 
-                    me.battle(&elf) + me.points()
-                })
-                .sum(),
-        )
+                me.battle(&elf) + me.points()
+            })
+            .sum())
     }
 }
 
-fn main() {
-    aoc::solution!(Day02)
-}
+aoc::run!(Day02);
 #[cfg(test)]
 mod tests {
     use crate::Day02 as day_02;
@@ -16,6 +16,7 @@ impl Day03 {
 
 impl Solution for Day03 {
     const TITLE: &'static str = "Rucksack Reorganization";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 3;
     type Input = Vec<String>;
     type P1 = usize;
@@ -25,53 +26,47 @@ impl Solution for Day03 {
         Ok(lines_to_owned(input))
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(
-            input
-                .iter()
-                .map(|line| {
-                    let (left, right) = line.split_at(&line.len() / 2);
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(input
+            .iter()
+            .map(|line| {
+                let (left, right) = line.split_at(&line.len() / 2);
 
-                    (
-                        left.chars().map(Day03::priority).collect::<HashSet<_>>(),
-                        right.chars().map(Day03::priority).collect::<HashSet<_>>(),
-                    )
-                })
-                .map(|(left, right)| {
-                    left.iter()
-                        .filter(|&value| right.contains(value))
-                        .sum::<usize>()
-                })
-                .sum(),
-        )
+                (
+                    left.chars().map(Day03::priority).collect::<HashSet<_>>(),
+                    right.chars().map(Day03::priority).collect::<HashSet<_>>(),
+                )
+            })
+            .map(|(left, right)| {
+                left.iter()
+                    .filter(|&value| right.contains(value))
+                    .sum::<usize>()
+            })
+            .sum())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        Some(
-            input
-                .chunks_exact(3)
-                .map(|chunk| {
-                    if let [first, second, third] = chunk {
-                        let first: HashSet<_> = first.chars().map(Day03::priority).collect();
-                        let second: HashSet<_> = second.chars().map(Day03::priority).collect();
-                        let third: HashSet<_> = third.chars().map(Day03::priority).collect();
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Ok(input
+            .chunks_exact(3)
+            .map(|chunk| {
+                if let [first, second, third] = chunk {
+                    let first: HashSet<_> = first.chars().map(Day03::priority).collect();
+                    let second: HashSet<_> = second.chars().map(Day03::priority).collect();
+                    let third: HashSet<_> = third.chars().map(Day03::priority).collect();
 
-                        first
-                            .iter()
-                            .filter(|&value| second.contains(value) && third.contains(value))
-                            .sum()
-                    } else {
-                        0
-                    }
-                })
-                .sum(),
-        )
+                    first
+                        .iter()
+                        .filter(|&value| second.contains(value) && third.contains(value))
+                        .sum()
+                } else {
+                    0
+                }
+            })
+            .sum())
     }
 }
 
-fn main() {
-    aoc::solution!(Day03)
-}
+aoc::run!(Day03);
 #[cfg(test)]
 mod tests {
     use crate::Day03 as day_03;
@@ -1,9 +1,11 @@
 use aoc::Solution;
+use aoc_utils::interval::IntervalSet;
 
 struct Day04;
 
 impl Solution for Day04 {
     const TITLE: &'static str = "Camp Cleanup";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 4;
     type Input = Vec<((usize, usize), (usize, usize))>;
     type P1 = usize;
@@ -28,36 +30,30 @@ impl Solution for Day04 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        let clamp =
-            |a: &(usize, usize), b: &(usize, usize)| (a.0.clamp(b.0, b.1), a.1.clamp(b.0, b.1));
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(input
+            .iter()
+            .filter(|(left, right)| {
+                let (left, right) = (sections(left), sections(right));
 
-        Some(
-            input
-                .iter()
-                .filter(|(left, right)| clamp(left, right).eq(left) || clamp(right, left).eq(right))
-                .count(),
-        )
+                left.is_subset(&right) || right.is_subset(&left)
+            })
+            .count())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        Some(
-            input
-                .iter()
-                .filter(|(left, right)| {
-                    let mut left = (left.0)..=(left.1);
-                    let right = (right.0)..=(right.1);
-
-                    left.any(|x| right.contains(&x))
-                })
-                .count(),
-        )
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Ok(input
+            .iter()
+            .filter(|(left, right)| !sections(left).is_disjoint(&sections(right)))
+            .count())
     }
 }
 
-fn main() {
-    aoc::solution!(Day04)
+fn sections(&(start, end): &(usize, usize)) -> IntervalSet<usize> {
+    IntervalSet::from(start..=end)
 }
+
+aoc::run!(Day04);
 #[cfg(test)]
 mod tests {
     use crate::Day04 as day_04;
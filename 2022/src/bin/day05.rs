@@ -7,6 +7,7 @@ struct Day05;
 
 impl Solution for Day05 {
     const TITLE: &'static str = "Supply Stacks";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 5;
     type Input = (Vec<VecDeque<String>>, Vec<(usize, usize, usize)>);
     type P1 = String;
@@ -66,28 +67,33 @@ impl Solution for Day05 {
         Ok((stacks, instructions))
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let (stacks, instructions) = input;
         let mut stacks = stacks.to_owned();
 
         for (count, from, to) in instructions {
             for _ in 0..*count {
-                let from = stacks.get_mut(from - 1)?.pop_back()?;
-
-                stacks.get_mut(to - 1)?.push_back(from);
+                let from = stacks
+                    .get_mut(from - 1)
+                    .ok_or(SolutionError::NoAnswer)?
+                    .pop_back()
+                    .ok_or(SolutionError::NoAnswer)?;
+
+                stacks
+                    .get_mut(to - 1)
+                    .ok_or(SolutionError::NoAnswer)?
+                    .push_back(from);
             }
         }
 
-        Some(
-            stacks
-                .iter()
-                .filter_map(|stack| stack.back())
-                .cloned()
-                .collect::<String>(),
-        )
+        Ok(stacks
+            .iter()
+            .filter_map(|stack| stack.back())
+            .cloned()
+            .collect::<String>())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let (stacks, instructions) = input;
         let mut stacks = stacks.to_owned();
 
@@ -95,7 +101,11 @@ impl Solution for Day05 {
             let mut buffer = vec![];
 
             for _ in 0..*count {
-                let from = stacks.get_mut(from - 1)?.pop_back()?;
+                let from = stacks
+                    .get_mut(from - 1)
+                    .ok_or(SolutionError::NoAnswer)?
+                    .pop_back()
+                    .ok_or(SolutionError::NoAnswer)?;
 
                 buffer.push(from);
             }
@@ -105,19 +115,15 @@ impl Solution for Day05 {
             })
         }
 
-        Some(
-            stacks
-                .iter()
-                .filter_map(|stack| stack.back())
-                .cloned()
-                .collect::<String>(),
-        )
+        Ok(stacks
+            .iter()
+            .filter_map(|stack| stack.back())
+            .cloned()
+            .collect::<String>())
     }
 }
 
-fn main() {
-    aoc::solution!(Day05)
-}
+aoc::run!(Day05);
 #[cfg(test)]
 mod tests {
     use crate::Day05 as day_05;
@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use itertools::Itertools;
 
@@ -22,6 +23,7 @@ impl Day06 {
 
 impl Solution for Day06 {
     const TITLE: &'static str = "Tuning Trouble";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 6;
     type Input = String;
     type P1 = usize;
@@ -31,18 +33,16 @@ impl Solution for Day06 {
         Ok(input.to_owned())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Day06::get_marker(input, 4)
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Day06::get_marker(input, 4).ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        Day06::get_marker(input, 14)
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Day06::get_marker(input, 14).ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day06)
-}
+aoc::run!(Day06);
 #[cfg(test)]
 mod tests {
     use crate::Day06 as day_06;
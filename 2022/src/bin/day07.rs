@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -40,6 +41,7 @@ impl TryFrom<&str> for Entry {
 
 impl Solution for Day07 {
     const TITLE: &'static str = "No Space Left On Device";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 7;
     type Input = HashMap<Vec<String>, usize>;
     type P1 = usize;
@@ -87,23 +89,23 @@ impl Solution for Day07 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(
-            input
-                .iter()
-                .filter_map(|(_, size)| match size {
-                    size if size <= &100000 => Some(size),
-                    _ => None,
-                })
-                .sum::<usize>(),
-        )
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(input
+            .iter()
+            .filter_map(|(_, size)| match size {
+                size if size <= &100000 => Some(size),
+                _ => None,
+            })
+            .sum::<usize>())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         const FS_SIZE: usize = 70000000;
         const UPDATE_SIZE: usize = 30000000;
 
-        let total_size = input.get(&vec!["/".to_owned()])?;
+        let total_size = input
+            .get(&vec!["/".to_owned()])
+            .ok_or(SolutionError::NoAnswer)?;
         let target = UPDATE_SIZE - (FS_SIZE - total_size);
 
         input
@@ -114,12 +116,11 @@ impl Solution for Day07 {
             })
             .min()
             .cloned()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day07)
-}
+aoc::run!(Day07);
 #[cfg(test)]
 mod tests {
     use crate::Day07 as day_07;
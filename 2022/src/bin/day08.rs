@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 
 struct Day08;
@@ -50,6 +51,7 @@ impl Day08 {
 
 impl Solution for Day08 {
     const TITLE: &'static str = "Treetop Tree House";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 8;
     type Input = Vec<Vec<u8>>;
     type P1 = usize;
@@ -64,10 +66,11 @@ impl Solution for Day08 {
         Ok(grid)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let deltas = [(1, 0), (-1, 0), (0, 1), (0, -1)];
 
-        let edges_count = input.len() * 2 + input.first()?.len() * 2 - 4;
+        let edges_count =
+            input.len() * 2 + input.first().ok_or(SolutionError::NoAnswer)?.len() * 2 - 4;
         let mut count = 0;
 
         for row_index in 1..(input.len() - 1) {
@@ -82,10 +85,10 @@ impl Solution for Day08 {
             }
         }
 
-        Some(edges_count + count)
+        Ok(edges_count + count)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let deltas = [(1, 0), (-1, 0), (0, 1), (0, -1)];
 
         input
@@ -99,12 +102,11 @@ impl Solution for Day08 {
                 })
             })
             .max()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day08)
-}
+aoc::run!(Day08);
 #[cfg(test)]
 mod tests {
     use crate::Day08 as day_08;
@@ -69,6 +69,7 @@ impl Day09 {
 
 impl Solution for Day09 {
     const TITLE: &'static str = "Rope Bridge";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 9;
     type Input = Vec<Instruction>;
     type P1 = usize;
@@ -81,18 +82,16 @@ impl Solution for Day09 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(Day09::move_rope::<2>(input).len())
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(Day09::move_rope::<2>(input).len())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        Some(Day09::move_rope::<10>(input).len())
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Ok(Day09::move_rope::<10>(input).len())
     }
 }
 
-fn main() {
-    aoc::solution!(Day09)
-}
+aoc::run!(Day09);
 #[cfg(test)]
 mod tests {
     use crate::Day09 as day_09;
@@ -11,6 +11,7 @@ enum Instruction {
 
 impl Solution for Day10 {
     const TITLE: &'static str = "Cathode-Ray Tube";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 10;
     type Input = Vec<Instruction>;
     type P1 = i32;
@@ -35,7 +36,7 @@ impl Solution for Day10 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let (_, sum) = input.iter().take(220).zip(1..).fold(
             (1, 0),
             |(mut x, mut sum), (instruction, cycle)| {
@@ -52,10 +53,10 @@ impl Solution for Day10 {
             },
         );
 
-        Some(sum)
+        Ok(sum)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let (output, _) = input.iter().zip(0..).fold(
             (String::new(), 1),
             |(mut output, mut x), (instruction, cycle)| {
@@ -72,7 +73,7 @@ impl Solution for Day10 {
                 (output, x)
             },
         );
-        Some(format!(
+        Ok(format!(
             "\n{}",
             output
                 .chars()
@@ -84,9 +85,7 @@ impl Solution for Day10 {
     }
 }
 
-fn main() {
-    aoc::solution!(Day10)
-}
+aoc::run!(Day10);
 #[cfg(test)]
 mod tests {
     use crate::Day10 as day_10;
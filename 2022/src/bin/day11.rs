@@ -89,6 +89,7 @@ impl Day11 {
 
 impl Solution for Day11 {
     const TITLE: &'static str = "Monkey in the Middle";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 11;
     type Input = Vec<Monkey>;
     type P1 = usize;
@@ -115,37 +116,31 @@ impl Solution for Day11 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let result = Day11::rounds::<20>(input, |item| item / 3);
 
-        Some(
-            result
-                .iter()
-                .map(|(_, count)| count)
-                .sorted()
-                .skip(result.len() - 2)
-                .product(),
-        )
+        Ok(result
+            .iter()
+            .map(|(_, count)| count)
+            .sorted()
+            .skip(result.len() - 2)
+            .product())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let tests_products: usize = input.iter().map(|monkey| monkey.test).product();
         let result = Day11::rounds::<10000>(input, |item| item % tests_products);
 
-        Some(
-            result
-                .iter()
-                .map(|(_, count)| count)
-                .sorted()
-                .skip(result.len() - 2)
-                .product(),
-        )
+        Ok(result
+            .iter()
+            .map(|(_, count)| count)
+            .sorted()
+            .skip(result.len() - 2)
+            .product())
     }
 }
 
-fn main() {
-    aoc::solution!(Day11)
-}
+aoc::run!(Day11);
 #[cfg(test)]
 mod tests {
     use crate::Day11 as day_11;
@@ -38,19 +38,14 @@ impl Hill {
     }
 }
 
-struct Expedition<'a, T: Fn(&Coord) -> bool> {
+struct Expedition<'a> {
     hill: &'a Hill,
-    get_target: T,
-    start: Coord,
+    starts: Vec<Coord>,
 }
 
-impl<'a, T: Fn(&Coord) -> bool> Expedition<'a, T> {
-    pub fn new(hill: &'a Hill, start: Coord, get_target: T) -> Self {
-        Self {
-            hill,
-            get_target,
-            start,
-        }
+impl<'a> Expedition<'a> {
+    pub fn new(hill: &'a Hill, starts: Vec<Coord>) -> Self {
+        Self { hill, starts }
     }
 
     fn as_index(&self, coord: &Coord) -> usize {
@@ -76,9 +71,16 @@ impl<'a, T: Fn(&Coord) -> bool> Expedition<'a, T> {
     }
 }
 
-impl<T: Fn(&Coord) -> bool> Graph<usize> for Expedition<'_, T> {
+impl Graph<usize> for Expedition<'_> {
     fn start(&self) -> Option<usize> {
-        Some(self.as_index(&self.start))
+        self.starts.first().map(|start| self.as_index(start))
+    }
+
+    fn starts(&self) -> Vec<usize> {
+        self.starts
+            .iter()
+            .map(|start| self.as_index(start))
+            .collect()
     }
 
     fn adjacent(&self, node: &usize) -> Option<Vec<usize>> {
@@ -101,9 +103,7 @@ impl<T: Fn(&Coord) -> bool> Graph<usize> for Expedition<'_, T> {
     }
 
     fn is_target(&self, node: &usize) -> bool {
-        let test = &self.get_target;
-
-        test(&self.as_coord(*node))
+        self.as_coord(*node) == self.hill.top
     }
 
     fn estimated_size(&self) -> usize {
@@ -113,6 +113,7 @@ impl<T: Fn(&Coord) -> bool> Graph<usize> for Expedition<'_, T> {
 
 impl Solution for Day12 {
     const TITLE: &'static str = "Hill Climbing Algorithm";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 12;
     type Input = Hill;
     type P1 = i32;
@@ -154,29 +155,32 @@ impl Solution for Day12 {
         })
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        let expedition = Expedition::new(input, input.start, |current| current == &input.top);
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        let expedition = Expedition::new(input, vec![input.start]);
 
         dijkstra::solve(&expedition, |current, next| {
             expedition.get_cost(*current, *next)
         })
         .map(|(cost, _)| cost)
+        .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        let expedition =
-            Expedition::new(input, input.top, |current| input.get(current) == Some(&'a'));
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        let lowest = (0..input.height)
+            .flat_map(|y| (0..input.width).map(move |x| Coord { x, y }))
+            .filter(|coord| input.get(coord) == Some(&'a'))
+            .collect();
+        let expedition = Expedition::new(input, lowest);
 
         dijkstra::solve(&expedition, |current, next| {
-            expedition.get_cost(*next, *current)
+            expedition.get_cost(*current, *next)
         })
         .map(|(cost, _)| cost)
+        .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day12)
-}
+aoc::run!(Day12);
 #[cfg(test)]
 mod tests {
     use crate::Day12 as day_12;
@@ -28,6 +28,7 @@ impl Day13 {
 
 impl Solution for Day13 {
     const TITLE: &'static str = "Distress Signal";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 13;
     type Input = Vec<Value>;
     type P1 = usize;
@@ -41,7 +42,7 @@ impl Solution for Day13 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let count = input
             .iter()
             .tuples::<(_, _)>()
@@ -49,27 +50,23 @@ impl Solution for Day13 {
             .map(|index| index + 1)
             .sum();
 
-        Some(count)
+        Ok(count)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let markers = [json!([[2]]), json!([[6]])];
 
-        Some(
-            input
-                .iter()
-                .merge_by(markers.iter(), |a, b| Day13::cmp(a, b).is_le())
-                .sorted_by(|a, b| Day13::cmp(a, b))
-                .enumerate()
-                .filter_map(|(index, value)| markers.contains(value).then_some(index + 1))
-                .product(),
-        )
+        Ok(input
+            .iter()
+            .merge_by(markers.iter(), |a, b| Day13::cmp(a, b).is_le())
+            .sorted_by(|a, b| Day13::cmp(a, b))
+            .enumerate()
+            .filter_map(|(index, value)| markers.contains(value).then_some(index + 1))
+            .product())
     }
 }
 
-fn main() {
-    aoc::solution!(Day13)
-}
+aoc::run!(Day13);
 #[cfg(test)]
 mod tests {
     use crate::Day13 as day_13;
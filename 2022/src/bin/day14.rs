@@ -1,9 +1,10 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
+use aoc_utils::collections::{Coord, SparseGrid};
 use itertools::Itertools;
-use std::collections::HashMap;
 use std::env;
 
-use crate::cave::{Cave, Tile};
+use self::cave::{Cave, Tile};
 
 use itertools::FoldWhile::{Continue, Done};
 
@@ -15,14 +16,9 @@ struct Day14;
 
 mod cave {
     use aoc::solution::SolutionError;
-    use aoc_utils::dijkstra::Boundaries;
-    use itertools::Itertools;
-    use shared::Point as PointT;
-    use std::collections::HashMap;
+    use aoc_utils::collections::{Bounds, Coord, SparseGrid};
     use std::ops::{Deref, DerefMut};
 
-    type Point = PointT<usize>;
-
     #[derive(Debug, Copy, Clone)]
     pub enum Tile {
         Sand,
@@ -31,18 +27,19 @@ mod cave {
 
     #[derive(Debug, Clone)]
     pub struct Cave {
-        boundaries: Boundaries,
-        tiles: HashMap<Point, Tile>,
+        /// Depth of the lowest rock, the floor being `bottom` below it.
+        height: i64,
+        tiles: SparseGrid<Tile>,
     }
 
     impl Cave {
-        pub fn height(&self) -> usize {
-            self.boundaries.height
+        pub fn height(&self) -> i64 {
+            self.height
         }
     }
 
     impl Deref for Cave {
-        type Target = HashMap<Point, Tile>;
+        type Target = SparseGrid<Tile>;
 
         fn deref(&self) -> &Self::Target {
             &self.tiles
@@ -56,62 +53,45 @@ mod cave {
     }
 
     impl Cave {
-        pub fn get_tile(&self, point: &Point, bottom: Option<usize>) -> Option<&Tile> {
+        pub fn get_tile(&self, point: &Coord, bottom: Option<i64>) -> Option<&Tile> {
             match self.get(point) {
                 Some(tile) => Some(tile),
-                None => match (point.y(), bottom) {
-                    (j, Some(depth)) if j == self.boundaries.height + depth => Some(&Tile::Rock),
+                None => match (point.1, bottom) {
+                    (j, Some(depth)) if j == self.height + depth => Some(&Tile::Rock),
                     _ => None,
                 },
             }
         }
 
-        pub fn print(&self, leak: Point, bottom: Option<usize>) -> String {
+        pub fn print(&self, leak: Coord, bottom: Option<i64>) -> String {
             let depth = bottom.unwrap_or(0);
+            let Some(bounds) = self.bounds() else {
+                return String::new();
+            };
+            let bounds = Bounds {
+                min: (bounds.min.0 - depth, 0),
+                max: (bounds.max.0 + depth, self.height + depth),
+            };
 
-            (self.boundaries.y..=self.boundaries.height + depth)
-                .map(|j| {
-                    (self.boundaries.x - depth..=self.boundaries.width + depth)
-                        .map(|i| match Point::new(i, j) {
-                            p if p == leak => '+',
-                            p => match self.get_tile(&p, bottom) {
-                                Some(Tile::Sand) => 'o',
-                                Some(Tile::Rock) => '#',
-                                None => '.',
-                            },
-                        })
-                        .join("")
-                })
-                .join("\n")
+            self.render_within(&bounds, |p, _| match p {
+                p if p == leak => '+',
+                p => match self.get_tile(&p, bottom) {
+                    Some(Tile::Sand) => 'o',
+                    Some(Tile::Rock) => '#',
+                    None => '.',
+                },
+            })
         }
     }
 
-    impl TryFrom<&HashMap<Point, Tile>> for Cave {
+    impl TryFrom<&SparseGrid<Tile>> for Cave {
         type Error = SolutionError;
 
-        fn try_from(tiles: &HashMap<Point, Tile>) -> Result<Self, Self::Error> {
-            let (x, width) = tiles
-                .keys()
-                .map(|point| point.x())
-                .minmax()
-                .into_option()
-                .ok_or(SolutionError::ParseError)?;
-
-            let height = tiles
-                .keys()
-                .map(|point| point.y())
-                .max()
-                .ok_or(SolutionError::ParseError)?;
-
-            let boundaries = Boundaries {
-                x,
-                y: 0,
-                width,
-                height,
-            };
+        fn try_from(tiles: &SparseGrid<Tile>) -> Result<Self, Self::Error> {
+            let bounds = tiles.bounds().ok_or(SolutionError::ParseError)?;
 
             Ok(Self {
-                boundaries,
+                height: bounds.max.1,
                 tiles: tiles.to_owned(),
             })
         }
@@ -119,22 +99,24 @@ mod cave {
 }
 
 impl Day14 {
-    fn simulate(initial: Cave, leak: Point, bottom: Option<usize>) -> Cave {
+    const LEAK: Coord = (500, 0);
+
+    fn simulate(initial: Cave, leak: Coord, bottom: Option<i64>) -> Cave {
         let max_y = initial.height() + bottom.unwrap_or_default();
 
         (0..)
-            .fold_while((initial, leak), |(mut cave, sand), _| {
-                if sand.y() >= max_y {
-                    return Done((cave, sand));
+            .fold_while((initial, leak), |(mut cave, (x, y)), _| {
+                if y >= max_y {
+                    return Done((cave, (x, y)));
                 }
 
                 if let Some(Tile::Sand) = cave.get(&leak) {
-                    return Done((cave, sand));
+                    return Done((cave, (x, y)));
                 }
 
-                let down = Point::new(sand.x(), sand.y() + 1);
-                let left = Point::new(sand.x() - 1, sand.y() + 1);
-                let right = Point::new(sand.x() + 1, sand.y() + 1);
+                let down = (x, y + 1);
+                let left = (x - 1, y + 1);
+                let right = (x + 1, y + 1);
 
                 match (
                     cave.get_tile(&down, bottom),
@@ -145,7 +127,7 @@ impl Day14 {
                     (_, None, _) => Continue((cave, left)),
                     (_, _, None) => Continue((cave, right)),
                     (Some(_), _, _) => {
-                        cave.insert(sand, Tile::Sand);
+                        cave.insert((x, y), Tile::Sand);
 
                         Continue((cave, leak))
                     }
@@ -158,56 +140,71 @@ impl Day14 {
 
 impl Solution for Day14 {
     const TITLE: &'static str = "Regolith Reservoir";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 14;
-    type Input = HashMap<Point, Tile>;
+    type Input = SparseGrid<Tile>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        Ok(input
+        let paths = input
             .lines()
-            .flat_map(|line| {
+            .map(|line| {
                 line.split(" -> ")
-                    .filter_map(|point| point.parse().ok())
-                    .tuple_windows::<(Point, _)>()
+                    .map(|point| {
+                        point
+                            .parse()
+                            .map_err(|err: SolutionError| err.within(input, point))
+                    })
+                    .collect::<aoc::solution::Result<Vec<Point>>>()
+            })
+            .collect::<aoc::solution::Result<Vec<_>>>()?;
+
+        Ok(paths
+            .iter()
+            .flat_map(|path| path.iter().tuple_windows())
+            .flat_map(|(p1, p2)| p1.line_to(p2))
+            .map(|point| {
+                let (x, y) = point.xy();
+
+                ((x as i64, y as i64), Tile::Rock)
             })
-            .flat_map(|(p1, p2)| p1.line_to(&p2).map(|point| (point, Tile::Rock)))
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        let cave = Cave::try_from(input).ok()?;
-        let leak = Point::new(500, 0);
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        let cave = Cave::try_from(input)?;
         let rock_count = cave.len();
 
-        Some(Day14::simulate(cave, leak, None).len() - rock_count)
+        Ok(Day14::simulate(cave, Day14::LEAK, None).len() - rock_count)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        let cave = Cave::try_from(input).ok()?;
-        let leak = Point::new(500, 0);
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        let cave = Cave::try_from(input)?;
         let rock_count = cave.len();
 
-        Some(Day14::simulate(cave, leak, Some(2)).len() - rock_count)
+        Ok(Day14::simulate(cave, Day14::LEAK, Some(2)).len() - rock_count)
     }
 }
 
 fn main() {
     match env::args().nth(1) {
         Some(query) if query == "--print" => {
-            let input = Day14::parse(&Day14::get_input().unwrap()).unwrap();
+            let input = Day14::parse(&aoc::input!(Day14).unwrap()).unwrap();
             let cave = Cave::try_from(&input).unwrap();
-            let leak = Point::new(500, 0);
 
             println!("==PART 1===");
-            println!("{}", cave.print(leak, None));
+            println!("{}", cave.print(Day14::LEAK, None));
             println!("\n==PART 2===");
-            println!("{}", cave.print(leak, Some(2)));
+            println!("{}", cave.print(Day14::LEAK, Some(2)));
         }
         _ => {}
     }
     aoc::solution!(Day14)
 }
+
+aoc::register!(Day14);
+
 #[cfg(test)]
 mod tests {
     use crate::Day14 as day_14;
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
+use aoc::solution::SolutionError;
 use aoc::Solution;
-use itertools::Itertools;
+use aoc_utils::interval::IntervalSet;
 use rayon::prelude::*;
 
 use shared::Point as PointT;
@@ -30,6 +31,7 @@ impl<const T: usize, const A: usize> Day15<T, A> {
 
 impl<const TARGET: usize, const AREA: usize> Solution for Day15<TARGET, AREA> {
     const TITLE: &'static str = "Beacon Exclusion Zone";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 15;
     type Input = HashMap<Point, Tile>;
     type P1 = usize;
@@ -59,75 +61,71 @@ impl<const TARGET: usize, const AREA: usize> Solution for Day15<TARGET, AREA> {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        let (min, max) = input
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        let row = TARGET as i32;
+        let covered: IntervalSet<i32> = input
             .iter()
-            .filter_map(|(coord, tile)| match tile {
-                Tile::Sensor(radius) => Some([coord.x() - radius, coord.x() + radius]),
+            .filter_map(|(sensor, tile)| match tile {
+                Tile::Sensor(radius) => {
+                    let reach = radius - (sensor.y() - row).abs();
+
+                    Some(sensor.x() - reach..=sensor.x() + reach)
+                }
                 _ => None,
             })
-            .flatten()
-            .minmax()
-            .into_option()?;
-
-        Some(
-            (min..max)
-                .map(|x| Point::new(x, TARGET as i32))
-                .filter(|target| !input.contains_key(target))
-                .filter(|target| {
-                    input.iter().any(|(current, tile)| match tile {
-                        Tile::Sensor(radius) => {
-                            Day15::<TARGET, AREA>::manhattan_distance(target, current) <= *radius
-                        }
-                        _ => false,
-                    })
-                })
-                .count(),
-        )
+            .collect();
+        let occupied: IntervalSet<i32> = input
+            .keys()
+            .filter(|point| point.y() == row)
+            .map(|point| point.x()..=point.x())
+            .collect();
+
+        Ok(covered.difference(&occupied).len() as usize)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let sensors: Vec<_> = input
             .iter()
             .filter(|(_, tile)| matches!(tile, Tile::Sensor(_)))
             .collect();
 
-        let point = sensors.iter().find_map(|(sensor, tile)| match tile {
-            Tile::Sensor(radius) => sensor
-                .circle(radius + 1)
-                .into_par_iter()
-                .filter(|circle| {
-                    let (x, y) = circle.xy();
-                    let min = 0;
-                    let max = AREA as i32;
-
-                    min <= x && x <= max && min <= y && y <= max
-                })
-                .filter(|circle| !input.contains_key(circle))
-                .find_any(|circle| {
-                    sensors
-                        .iter()
-                        .all(|(other, other_sensor)| match other_sensor {
-                            Tile::Sensor(test_radius) => {
-                                Day15::<TARGET, AREA>::manhattan_distance(other, circle)
-                                    > *test_radius
-                            }
-                            _ => unreachable!(),
-                        })
-                }),
-            _ => unreachable!(),
-        })?;
-
-        Some(point.x() as usize * 4000000 + point.y() as usize)
+        let point = sensors
+            .iter()
+            .find_map(|(sensor, tile)| match tile {
+                Tile::Sensor(radius) => sensor
+                    .circle(radius + 1)
+                    .into_par_iter()
+                    .filter(|circle| {
+                        let (x, y) = circle.xy();
+                        let min = 0;
+                        let max = AREA as i32;
+
+                        min <= x && x <= max && min <= y && y <= max
+                    })
+                    .filter(|circle| !input.contains_key(circle))
+                    .find_any(|circle| {
+                        sensors
+                            .iter()
+                            .all(|(other, other_sensor)| match other_sensor {
+                                Tile::Sensor(test_radius) => {
+                                    Day15::<TARGET, AREA>::manhattan_distance(other, circle)
+                                        > *test_radius
+                                }
+                                _ => unreachable!(),
+                            })
+                    }),
+                _ => unreachable!(),
+            })
+            .ok_or(SolutionError::NoAnswer)?;
+
+        Ok(point.x() as usize * 4000000 + point.y() as usize)
     }
 }
 
-fn main() {
-    type Day = Day15<2000000, 4000000>;
-    // type Day = Day15<10, 20>;
+type Day15Actual = Day15<2000000, 4000000>;
+
+aoc::run!(Day15Actual);
 
-    aoc::solution!(Day)
-}
 #[cfg(test)]
 mod tests {
     use crate::Day15;
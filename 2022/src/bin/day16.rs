@@ -3,10 +3,11 @@ use std::collections::BinaryHeap;
 use std::error::Error;
 use std::str::FromStr;
 
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_lines, SolutionError};
 use aoc::Solution;
 
 use aoc_utils::is_flag;
+use aoc_utils::pathfinding::{compress, Graph};
 use itertools::Itertools;
 
 struct Day16;
@@ -83,6 +84,25 @@ impl Node {
     }
 }
 
+struct Tunnels<'a> {
+    tunnels: &'a [Vec<usize>],
+    from: usize,
+}
+
+impl Graph<usize> for Tunnels<'_> {
+    fn start(&self) -> Option<usize> {
+        Some(self.from)
+    }
+
+    fn adjacent(&self, node: &usize) -> Option<Vec<usize>> {
+        self.tunnels.get(*node).cloned()
+    }
+
+    fn is_target(&self, _node: &usize) -> bool {
+        false
+    }
+}
+
 #[derive(Debug)]
 struct Cave {
     distance_matrix: Vec<Vec<u16>>,
@@ -99,36 +119,41 @@ impl FromIterator<Valve> for Cave {
 
 impl Cave {
     fn new(valves: Vec<Valve>) -> Self {
-        let size = valves.len();
-        let distance_matrix = {
-            let mut matrix = vec![vec![u16::MAX; size]; size];
-
-            valves.iter().enumerate().for_each(|(i, valve)| {
+        let tunnels: Vec<Vec<_>> = valves
+            .iter()
+            .map(|valve| {
                 valve
                     .nodes
                     .iter()
                     .filter_map(|name| valves.iter().position(|valve| &valve.name == name))
-                    .for_each(|j| matrix[i][j] = 1);
-                matrix[i][i] = 0;
-            });
-
-            (0..size)
-                .cartesian_product(0..size)
-                .cartesian_product(0..size)
-                .for_each(|((k, i), j)| {
-                    if let Some(dist) = matrix[k][i].checked_add(matrix[k][j]) {
-                        matrix[i][j] = dist.min(matrix[i][j]);
-                    }
-                });
-
-            matrix
+                    .collect()
+            })
+            .collect();
+
+        let start = valves.iter().position(|valve| valve.name == "AA").unwrap();
+
+        let tunnels = Tunnels {
+            tunnels: &tunnels,
+            from: start,
         };
+        let compressed = compress(&tunnels, |_, _| Some(1), |&index| valves[index].rate > 0);
+
+        let distance_matrix = compressed
+            .distance_matrix()
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|dist| dist.unwrap_or(u16::MAX))
+                    .collect()
+            })
+            .collect();
 
-        let start = valves
+        let start = compressed.index_of(&start).map(Index).unwrap();
+        let valves: Vec<_> = compressed
+            .nodes()
             .iter()
-            .position(|valve| valve.name == "AA")
-            .map(Index)
-            .unwrap();
+            .map(|&index| valves[index].clone())
+            .collect();
 
         let graph = valves
             .iter()
@@ -266,16 +291,17 @@ impl Cave {
 
 impl Solution for Day16 {
     const TITLE: &'static str = "Proboscidea Volcanium";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 16;
     type Input = Cave;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input.lines().map(Valve::from_str).collect()
+        parse_lines(input).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let take_only_leafs = |graph: &[Node], budget: &usize| graph.is_empty() || budget <= &2;
 
         is_print().then(|| input.print_matrix());
@@ -287,10 +313,14 @@ impl Solution for Day16 {
 
         is_flag("-p1").then(|| input.print_paths(&paths));
 
-        paths.iter().map(|(_, e)| *e).max()
+        paths
+            .iter()
+            .map(|(_, e)| *e)
+            .max()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let take_incomplete_only = &|remaining: &[Node], _: &usize| !remaining.is_empty();
 
         let paths = input.solve(26, take_incomplete_only);
@@ -311,6 +341,7 @@ impl Solution for Day16 {
             .take(10_000)
             .map(|((_, a), (_, b))| *a + *b)
             .max()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -333,6 +364,8 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+aoc::register!(Day16);
+
 #[cfg(test)]
 mod tests {
     use crate::Day16 as day_16;
@@ -1,12 +1,16 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
 
 use aoc::solution::SolutionError;
 use aoc::Solution;
+use aoc_utils::collections::{Bounds, SparseGrid};
 use itertools::Itertools;
 
 type Point = (i64, i64);
 
+/// The rocks at rest, `y` going up from the floor.
+type Tower = SparseGrid<()>;
+
 struct Day17;
 
 #[derive(Debug, Clone)]
@@ -62,38 +66,38 @@ impl Shape {
 
 impl Day17 {
     #[allow(unused)]
-    fn print(cache: &HashSet<Point>, shape: Option<&Shape>, start: usize, height: usize) {
+    fn print(tower: &Tower, shape: Option<&Shape>, start: usize, height: usize) {
+        let bounds = Bounds {
+            min: (0, start as i64),
+            max: (6, height as i64 - 1),
+        };
+        let rows = tower.render_within(&bounds, |p, rock| match (rock, shape) {
+            (Some(_), _) => '#',
+            (_, Some(s)) if s.0.contains(&p) => '@',
+            _ => '.',
+        });
+
+        // rendered top row first, with y going down
         println!(
             "{}\n+-------+",
-            (start..height)
+            rows.lines()
+                .zip(bounds.ys())
+                .collect_vec()
+                .into_iter()
                 .rev()
-                .map(|y| format!(
-                    "|{}| {}",
-                    (0..7)
-                        .map(|x| {
-                            let p = (x, y as i64);
-
-                            match cache.get(&p) {
-                                Some(_) => "#",
-                                _ => match shape {
-                                    Some(s) => match s.0.contains(&p) {
-                                        true => "@",
-                                        _ => ".",
-                                    },
-                                    _ => ".",
-                                },
-                            }
-                        })
-                        .join(""),
-                    y
-                ))
+                .map(|(row, y)| format!("|{row}| {y}"))
                 .join("\n")
         );
     }
+
+    /// Number of rows of the tower, up to its highest rock.
+    fn height(tower: &Tower) -> usize {
+        tower.bounds().map_or(0, |bounds| bounds.max.1 as usize + 1)
+    }
 }
 
 impl Day17 {
-    fn can_move(pos: &(i64, i64), shape: &Shape, map: &HashSet<Point>, offset: Point) -> bool {
+    fn can_move(pos: &(i64, i64), shape: &Shape, map: &Tower, offset: Point) -> bool {
         shape.iter().all(|point| {
             let next_x = point.0 + pos.0 + offset.0;
             let next_y = point.1 + pos.1 + offset.1;
@@ -102,12 +106,7 @@ impl Day17 {
         })
     }
 
-    fn try_move(
-        pos: &mut (i64, i64),
-        shape: &Shape,
-        map: &HashSet<Point>,
-        offset: impl Into<Point>,
-    ) {
+    fn try_move(pos: &mut (i64, i64), shape: &Shape, map: &Tower, offset: impl Into<Point>) {
         let offset = offset.into();
 
         if Self::can_move(pos, shape, map, offset) {
@@ -116,13 +115,8 @@ impl Day17 {
         }
     }
 
-    fn fall(
-        shape: &Shape,
-        cache: &mut HashSet<Point>,
-        height: &mut usize,
-        mut move_factory: impl FnMut() -> i64,
-    ) {
-        let mut pos = (2, *height as i64 + 3);
+    fn fall(shape: &Shape, cache: &mut Tower, mut move_factory: impl FnMut() -> i64) {
+        let mut pos = (2, Day17::height(cache) as i64 + 3);
 
         let mut is_first = true;
         while Day17::can_move(&pos, shape, cache, (0, -1)) {
@@ -136,12 +130,11 @@ impl Day17 {
             Day17::try_move(&mut pos, shape, cache, (shift, 0));
         }
 
-        shape.iter().for_each(|point| {
-            let point = (point.0 + pos.0, point.1 + pos.1);
-
-            cache.insert(point);
-            *height = (*height).max((point.1 + 1) as usize);
-        });
+        cache.extend(
+            shape
+                .iter()
+                .map(|point| ((point.0 + pos.0, point.1 + pos.1), ())),
+        );
     }
 
     fn solve(target: usize, shapes: &[Shape], moves: &[i64]) -> Option<usize> {
@@ -149,18 +142,19 @@ impl Day17 {
         let mut count = 0;
 
         let mut height = 0;
-        let mut cache = HashSet::new();
+        let mut cache = Tower::new();
         let mut cycle = HashMap::new();
         let mut cycle_height = 0;
 
         while count < target {
             let shape = &shapes[count % shapes.len()];
-            Day17::fall(shape, &mut cache, &mut height, || {
+            Day17::fall(shape, &mut cache, || {
                 let offset = moves[moves_pos];
 
                 moves_pos = (moves_pos + 1) % moves.len();
                 offset
             });
+            height = Day17::height(&cache);
 
             // Check for Cycle
             if cycle_height == 0 {
@@ -194,6 +188,7 @@ impl Day17 {
 
 impl Solution for Day17 {
     const TITLE: &'static str = "Pyroclastic Flow";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 17;
     type Input = ([Shape; 5], Vec<i64>);
     type P1 = usize;
@@ -221,22 +216,20 @@ impl Solution for Day17 {
         Ok((shapes, moves))
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let (shapes, moves) = input;
 
-        Day17::solve(2022, shapes, moves)
+        Day17::solve(2022, shapes, moves).ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let (shapes, moves) = input;
 
-        Day17::solve(1_000_000_000_000, shapes, moves)
+        Day17::solve(1_000_000_000_000, shapes, moves).ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day17)
-}
+aoc::run!(Day17);
 #[cfg(test)]
 mod tests {
     use crate::Day17 as day_17;
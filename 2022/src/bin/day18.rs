@@ -1,8 +1,9 @@
 use std::collections::{HashSet, VecDeque};
 use std::str::FromStr;
 
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_lines, SolutionError};
 use aoc::Solution;
+use aoc_utils::cuboid::Cuboid;
 use itertools::Itertools;
 
 struct Day18;
@@ -47,62 +48,53 @@ impl Point3 {
         ]
     }
 
-    fn within(&self, min: &Self, max: &Self) -> bool {
-        min.0 <= self.0
-            && self.0 <= max.0
-            && min.1 <= self.1
-            && self.1 <= max.1
-            && min.2 <= self.2
-            && self.2 <= max.2
+    fn coords(&self) -> [i8; 3] {
+        [self.0, self.1, self.2]
     }
 }
 
 impl Solution for Day18 {
     const TITLE: &'static str = "Boiling Boulders";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 18;
     type Input = HashSet<Point3>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input.lines().map(Point3::from_str).collect()
+        parse_lines(input).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(
-            input
-                .iter()
-                .map(|point| {
-                    point
-                        .neighbors()
-                        .into_iter()
-                        .filter(|neighbor| !input.contains(neighbor))
-                        .count()
-                })
-                .sum(),
-        )
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(input
+            .iter()
+            .map(|point| {
+                point
+                    .neighbors()
+                    .into_iter()
+                    .filter(|neighbor| !input.contains(neighbor))
+                    .count()
+            })
+            .sum())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        let x_range = input.iter().map(|p| p.0).minmax().into_option()?;
-        let y_range = input.iter().map(|p| p.1).minmax().into_option()?;
-        let z_range = input.iter().map(|p| p.2).minmax().into_option()?;
-
-        let min_range = Point3(x_range.0 - 1, y_range.0 - 1, z_range.0 - 1);
-        let max_range = Point3(x_range.1 + 1, y_range.1 + 1, z_range.1 + 1);
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        let bounds = Cuboid::bounding(input.iter().map(Point3::coords))
+            .ok_or(SolutionError::NoAnswer)?
+            .expand(1);
 
         let mut cache = HashSet::new();
         let mut queue = VecDeque::new();
         let mut count = 0;
 
-        queue.push_back(min_range.clone());
+        queue.push_back(Point3(bounds.start[0], bounds.start[1], bounds.start[2]));
 
         while let Some(air) = queue.pop_front() {
             if !cache.contains(&air) {
                 cache.insert(air.clone());
 
                 for neighbor in air.neighbors() {
-                    if neighbor.within(&min_range, &max_range) {
+                    if bounds.contains(&neighbor.coords()) {
                         if input.contains(&neighbor) {
                             count += 1;
                         } else {
@@ -113,13 +105,11 @@ impl Solution for Day18 {
             }
         }
 
-        Some(count)
+        Ok(count)
     }
 }
 
-fn main() {
-    aoc::solution!(Day18)
-}
+aoc::run!(Day18);
 #[cfg(test)]
 mod tests {
     use crate::Day18 as day_18;
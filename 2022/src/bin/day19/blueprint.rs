@@ -1,5 +1,5 @@
-use crate::factory::Factory;
-use crate::material::{MatCount, Material, RobotCost};
+use super::factory::Factory;
+use super::material::{MatCount, Material, RobotCost};
 use derive_more::From;
 use itertools::Itertools;
 use std::collections::{BinaryHeap, HashMap};
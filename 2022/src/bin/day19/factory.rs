@@ -5,8 +5,8 @@ use std::fmt::{Display, Formatter};
 use itertools::Itertools;
 use strum::IntoEnumIterator;
 
-use crate::blueprint::Blueprint;
-use crate::material::{MatCount, Material, RobotCount};
+use super::blueprint::Blueprint;
+use super::material::{MatCount, Material, RobotCount};
 
 #[derive(Debug, Clone)]
 pub struct Factory<'a> {
@@ -17,6 +17,7 @@ struct Day19;
 
 impl Solution for Day19 {
     const TITLE: &'static str = "Not Enough Minerals";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 19;
     type Input = Vec<Blueprint>;
     type P1 = usize;
@@ -78,32 +79,28 @@ impl Solution for Day19 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let budget = 24;
 
-        Some(
-            input
-                .par_iter()
-                .enumerate()
-                .fold(
-                    || 0,
-                    |sum, (index, bp)| sum + (index + 1) * bp.solve(budget),
-                )
-                .sum(),
-        )
+        Ok(input
+            .par_iter()
+            .enumerate()
+            .fold(
+                || 0,
+                |sum, (index, bp)| sum + (index + 1) * bp.solve(budget),
+            )
+            .sum())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let budget = 32;
 
-        Some(
-            input
-                .iter()
-                .take(3)
-                .par_bridge()
-                .map(|bp| bp.solve(budget))
-                .product(),
-        )
+        Ok(input
+            .iter()
+            .take(3)
+            .par_bridge()
+            .map(|bp| bp.solve(budget))
+            .product())
     }
 }
 
@@ -121,6 +118,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     };
     Ok(())
 }
+
+aoc::register!(Day19);
+
 #[cfg(test)]
 mod tests {
     use crate::Day19 as day19;
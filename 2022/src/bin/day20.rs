@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 
 struct Day20;
@@ -25,6 +26,7 @@ impl Day20 {
 
 impl Solution for Day20 {
     const TITLE: &'static str = "Grove Positioning System";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 20;
     type Input = Vec<(usize, i64)>;
     type P1 = i64;
@@ -38,19 +40,28 @@ impl Solution for Day20 {
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let mixed = Day20::mix(input.to_owned(), input);
-        let p0 = mixed.iter().position(|(_, x)| x == &0)?;
+        let p0 = mixed
+            .iter()
+            .position(|(_, x)| x == &0)
+            .ok_or(SolutionError::NoAnswer)?;
         let len = mixed.len() as i64;
 
-        let (_, a) = mixed.get(Day20::index(p0, 1000, len))?;
-        let (_, b) = mixed.get(Day20::index(p0, 2000, len))?;
-        let (_, c) = mixed.get(Day20::index(p0, 3000, len))?;
-
-        Some((a + b + c) as i64)
+        let (_, a) = mixed
+            .get(Day20::index(p0, 1000, len))
+            .ok_or(SolutionError::NoAnswer)?;
+        let (_, b) = mixed
+            .get(Day20::index(p0, 2000, len))
+            .ok_or(SolutionError::NoAnswer)?;
+        let (_, c) = mixed
+            .get(Day20::index(p0, 3000, len))
+            .ok_or(SolutionError::NoAnswer)?;
+
+        Ok((a + b + c) as i64)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         const DECRYPT_KEY: i64 = 811589153;
         let initial: Vec<_> = input
             .clone()
@@ -61,20 +72,27 @@ impl Solution for Day20 {
         let mixed = (0..10).fold(initial.to_owned(), |buffer, _i| {
             Day20::mix(buffer, &initial)
         });
-        let p0 = mixed.iter().position(|(_, x)| x == &0)?;
+        let p0 = mixed
+            .iter()
+            .position(|(_, x)| x == &0)
+            .ok_or(SolutionError::NoAnswer)?;
         let len = mixed.len() as i64;
 
-        let (_, a) = mixed.get(Day20::index(p0, 1000, len))?;
-        let (_, b) = mixed.get(Day20::index(p0, 2000, len))?;
-        let (_, c) = mixed.get(Day20::index(p0, 3000, len))?;
-
-        Some((a + b + c) as i64)
+        let (_, a) = mixed
+            .get(Day20::index(p0, 1000, len))
+            .ok_or(SolutionError::NoAnswer)?;
+        let (_, b) = mixed
+            .get(Day20::index(p0, 2000, len))
+            .ok_or(SolutionError::NoAnswer)?;
+        let (_, c) = mixed
+            .get(Day20::index(p0, 3000, len))
+            .ok_or(SolutionError::NoAnswer)?;
+
+        Ok((a + b + c) as i64)
     }
 }
 
-fn main() {
-    aoc::solution!(Day20)
-}
+aoc::run!(Day20);
 #[cfg(test)]
 mod tests {
     use crate::Day20 as day_20;
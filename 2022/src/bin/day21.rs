@@ -115,6 +115,7 @@ impl MonkeyMath {
 
 impl Solution for Day21 {
     const TITLE: &'static str = "Monkey Math";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 21;
     type Input = HashMap<String, MonkeyMath>;
     type P1 = i64;
@@ -133,13 +134,18 @@ impl Solution for Day21 {
             .collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        input.get("root")?.eval(input)
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        input
+            .get("root")
+            .ok_or(SolutionError::NoAnswer)?
+            .eval(input)
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         use MonkeyMath::*;
-        let path = MonkeyMath::rev_path(HUMAN_KEY, ROOT_KEY, input)?;
+        let path =
+            MonkeyMath::rev_path(HUMAN_KEY, ROOT_KEY, input).ok_or(SolutionError::NoAnswer)?;
 
         let tree = path.iter().tuple_windows::<(_, _)>().fold(
             input.clone(),
@@ -166,13 +172,14 @@ impl Solution for Day21 {
             },
         );
 
-        tree.get(HUMAN_KEY)?.eval(&tree)
+        tree.get(HUMAN_KEY)
+            .ok_or(SolutionError::NoAnswer)?
+            .eval(&tree)
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day21)
-}
+aoc::run!(Day21);
 #[cfg(test)]
 mod tests {
     use crate::Day21 as day_21;
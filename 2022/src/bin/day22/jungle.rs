@@ -7,8 +7,8 @@ use itertools::Itertools;
 
 use aoc_utils::dijkstra::Boundaries;
 
-use crate::dir::Dir;
-use crate::Tile;
+use super::dir::Dir;
+use super::Tile;
 
 pub trait Navigate {
     fn get(&self, current: (usize, usize), dir: Dir) -> Option<&Tile>;
@@ -26,14 +26,19 @@ impl Jungle {
         self.grid.get(coord)
     }
 
+    /// Leftmost open tile of the top row.
     pub fn top_left(&self) -> Option<(usize, usize)> {
-        let step = self.boundaries.width.max(self.boundaries.height) / 4;
-
         (0..self.boundaries.width)
-            .step_by(step)
-            .find(|x| self.grid.contains_key(&(*x, 0)))
+            .find(|x| self.grid.get(&(*x, 0)) == Some(&Tile::Open))
             .map(|x| (x, 0))
     }
+
+    /// Size of the faces of the cube the jungle is the net of, from its number of tiles.
+    pub fn face_size(&self) -> Option<usize> {
+        let size = ((self.grid.len() / 6) as f64).sqrt() as usize;
+
+        (size > 0 && 6 * size * size == self.grid.len()).then_some(size)
+    }
 }
 
 impl FromStr for Jungle {
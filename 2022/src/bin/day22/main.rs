@@ -10,12 +10,14 @@ use regex::Regex;
 use dir::Dir;
 use jungle::Jungle;
 
-use crate::jungle::{Navigate, Render};
-use crate::part1::SphereJungle;
+use self::jungle::{Navigate, Render};
+use self::part1::SphereJungle;
+use self::part2::CubeJungle;
 
 mod dir;
 mod jungle;
 mod part1;
+mod part2;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Tile {
@@ -37,9 +39,9 @@ impl FromStr for Instruction {
         match s {
             "R" => Ok(Instruction::Right),
             "L" => Ok(Instruction::Left),
-            digit => digit
+            steps => steps
                 .parse()
-                .map_err(|_| SolutionError::ParseError)
+                .map_err(|_| SolutionError::expected(s, s, "'L', 'R' or a number of steps"))
                 .map(Instruction::Forward),
         }
     }
@@ -84,6 +86,7 @@ impl Day22 {
 
 impl Solution for Day22 {
     const TITLE: &'static str = "Monkey Map";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 22;
     type Input = (Jungle, Vec<Instruction>);
     type P1 = usize;
@@ -91,16 +94,21 @@ impl Solution for Day22 {
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
         let lines = input.lines().collect_vec();
-        let re = Regex::new(r"(L|R|\d+)").unwrap();
+        let re = Regex::new(r"\d+|.").unwrap();
 
         let (grid, instructions) = lines
             .split(|line| line.is_empty())
             .collect_tuple::<(_, _)>()
             .ok_or(SolutionError::ParseError)?;
 
-        let instructions = re
-            .find_iter(&instructions.join(""))
-            .map(|m| m.as_str().parse())
+        let instructions = instructions
+            .iter()
+            .flat_map(|line| re.find_iter(line))
+            .map(|m| {
+                m.as_str()
+                    .parse()
+                    .map_err(|err: SolutionError| err.within(input, m.as_str()))
+            })
             .collect::<Result<_, _>>()?;
 
         let jungle = Jungle::from_str(&grid.join("\n"))?;
@@ -108,23 +116,34 @@ impl Solution for Day22 {
         Ok((jungle, instructions))
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let (jungle, instructions) = input;
         let mut path = HashMap::new();
 
         let score = Day22::solve(
-            jungle.top_left()?,
+            jungle.top_left().ok_or(SolutionError::NoAnswer)?,
             SphereJungle::new(jungle),
             instructions,
             &mut path,
         );
         is_print().then(|| println!("{}", Render::new(jungle, &path)));
 
-        score
+        score.ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(_input: &Self::Input) -> Option<Self::P2> {
-        None
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        let (jungle, instructions) = input;
+        let mut path = HashMap::new();
+
+        let score = Day22::solve(
+            jungle.top_left().ok_or(SolutionError::NoAnswer)?,
+            CubeJungle::new(jungle).ok_or(SolutionError::NoAnswer)?,
+            instructions,
+            &mut path,
+        );
+        is_print().then(|| println!("{}", Render::new(jungle, &path)));
+
+        score.ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -132,9 +151,7 @@ fn is_print() -> bool {
     is_flag("--print")
 }
 
-fn main() {
-    aoc::solution!(Day22)
-}
+aoc::run!(Day22);
 #[cfg(test)]
 mod tests {
     use crate::Day22 as day_22;
@@ -146,6 +163,77 @@ mod tests {
         day_22:
         - "        ...#\n        .#..\n        #...\n        ....\n...#.......#\n........#...\n..#....#....\n..........#.\n        ...#....\n        .....#..\n        .#......\n        ......#.\n\n10R5L5R10L4R5L5\n"
             => Some(6032)
-            => None
+            => Some(5031)
+    }
+
+    /// Every net of a cube, `#` being a face.
+    const NETS: [&str; 11] = [
+        "#\n####\n#",
+        "#\n#\n##\n #\n #",
+        "#\n###\n #\n #",
+        "#\n##\n ##\n #",
+        "#\n##\n #\n ##",
+        "#\n####\n #",
+        "#\n##\n ##\n  #",
+        "#\n####\n  #",
+        "#\n####\n   #",
+        " #\n###\n #\n #",
+        " #\n##\n ##\n #",
+    ];
+
+    #[test]
+    fn cube_nets() {
+        let size = 3;
+
+        for net in NETS {
+            let jungle: Jungle = net
+                .lines()
+                .flat_map(|row| {
+                    let row = row
+                        .replace(' ', &" ".repeat(size))
+                        .replace('#', &".".repeat(size));
+
+                    std::iter::repeat_n(row, size)
+                })
+                .join("\n")
+                .parse()
+                .expect("valid jungle");
+            let cube = CubeJungle::new(&jungle).expect("net of a cube");
+
+            for y in 0..jungle.boundaries.height {
+                for x in 0..jungle.boundaries.width {
+                    if jungle.get(&(x, y)).is_none() {
+                        continue;
+                    }
+
+                    for dir in Dir::iter() {
+                        let (next, turned) = cube.pos((x, y), dir).expect("adjacent tile");
+                        let (back, _) = cube.pos(next, turned.flip()).expect("adjacent tile");
+                        let around = (0..4 * size)
+                            .try_fold(((x, y), dir), |(pos, dir), _| cube.pos(pos, dir));
+
+                        assert_eq!((x, y), back, "stepping back in {net:?}");
+                        assert_eq!(Some(((x, y), dir)), around, "going around {net:?}");
+                    }
+                }
+            }
+        }
+
+        let jungle: Jungle = "..\n..".parse().expect("valid jungle");
+
+        assert!(CubeJungle::new(&jungle).is_none());
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let Err(SolutionError::InvalidInput(err)) = day_22::parse("...#\n.#..\n\n10R5X5\n") else {
+            panic!("expected invalid input")
+        };
+
+        assert_eq!((4, 5, "X"), (err.line, err.column, err.text.as_str()));
+        assert_eq!(
+            Some("'L', 'R' or a number of steps"),
+            err.expected.as_deref()
+        );
     }
 }
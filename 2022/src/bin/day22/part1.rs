@@ -1,9 +1,9 @@
 use aoc_utils::index;
 use itertools::Itertools;
 
-use crate::dir::Dir;
-use crate::jungle::{Jungle, Navigate};
-use crate::Tile;
+use super::dir::Dir;
+use super::jungle::{Jungle, Navigate};
+use super::Tile;
 
 pub struct SphereJungle<'a> {
     jungle: &'a Jungle,
@@ -13,7 +13,7 @@ pub struct SphereJungle<'a> {
 
 impl<'a> SphereJungle<'a> {
     pub fn new(jungle: &'a Jungle) -> Self {
-        let step = jungle.boundaries.width.max(jungle.boundaries.height) / 4;
+        let step = jungle.face_size().unwrap_or(1);
 
         let x_ranges = (0..jungle.boundaries.height)
             .filter_map(|y| {
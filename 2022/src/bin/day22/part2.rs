@@ -0,0 +1,160 @@
+use super::dir::Dir;
+use super::jungle::{Jungle, Navigate};
+use super::Tile;
+
+type Vec3 = [isize; 3];
+
+fn neg(v: Vec3) -> Vec3 {
+    v.map(|a| -a)
+}
+
+fn dot(a: Vec3, b: Vec3) -> isize {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// A face of the cube: where it is in the net, and how it is oriented once folded.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    /// Top left tile of the face in the net.
+    origin: (usize, usize),
+    /// Direction of the columns of the face on the cube.
+    x: Vec3,
+    /// Direction of the rows of the face on the cube.
+    y: Vec3,
+    /// Outward normal of the face.
+    normal: Vec3,
+}
+
+impl Face {
+    /// Direction on the cube of moving towards `dir` on the face.
+    fn towards(&self, dir: Dir) -> Vec3 {
+        match dir {
+            Dir::Right => self.x,
+            Dir::Down => self.y,
+            Dir::Left => neg(self.x),
+            Dir::Up => neg(self.y),
+        }
+    }
+
+    /// The face next to this one towards `dir` in the net, folded over their common edge.
+    fn fold(&self, dir: Dir, size: usize) -> Option<Face> {
+        let (dx, dy) = dir.forward();
+        let origin = (
+            self.origin.0.checked_add_signed(dx * size as isize)?,
+            self.origin.1.checked_add_signed(dy * size as isize)?,
+        );
+        let (x, y, normal) = match dir {
+            Dir::Right => (neg(self.normal), self.y, self.x),
+            Dir::Left => (self.normal, self.y, neg(self.x)),
+            Dir::Down => (self.x, neg(self.normal), self.y),
+            Dir::Up => (self.x, self.normal, neg(self.y)),
+        };
+
+        Some(Face {
+            origin,
+            x,
+            y,
+            normal,
+        })
+    }
+
+    /// Center of the tile `(i, j)` of the face, on a cube spanning `-size..=size` on each axis.
+    fn center(&self, (i, j): (usize, usize), size: usize) -> Vec3 {
+        let size = size as isize;
+        let (i, j) = (2 * i as isize + 1 - size, 2 * j as isize + 1 - size);
+
+        [0, 1, 2].map(|k| size * self.normal[k] + i * self.x[k] + j * self.y[k])
+    }
+}
+
+/// The jungle folded as a cube: walking off the edge of a face continues on the adjacent face.
+pub struct CubeJungle<'a> {
+    jungle: &'a Jungle,
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl<'a> CubeJungle<'a> {
+    /// Folds the net of the jungle, `None` when it isn't the net of a cube.
+    pub fn new(jungle: &'a Jungle) -> Option<Self> {
+        let size = jungle.face_size()?;
+        let origin = (0..jungle.boundaries.width)
+            .step_by(size)
+            .map(|x| (x, 0))
+            .find(|origin| jungle.get(origin).is_some())?;
+        let mut faces = vec![Face {
+            origin,
+            x: [1, 0, 0],
+            y: [0, 1, 0],
+            normal: [0, 0, -1],
+        }];
+        let mut queue = vec![0];
+
+        while let Some(face) = queue.pop() {
+            for dir in Dir::iter() {
+                let Some(next) = faces[face].fold(dir, size) else {
+                    continue;
+                };
+
+                if jungle.get(&next.origin).is_some()
+                    && faces.iter().all(|face| face.origin != next.origin)
+                {
+                    faces.push(next);
+                    queue.push(faces.len() - 1);
+                }
+            }
+        }
+
+        let folded = faces.len() == 6
+            && faces
+                .iter()
+                .all(|face| faces.iter().filter(|f| f.normal == face.normal).count() == 1);
+
+        folded.then_some(Self {
+            jungle,
+            size,
+            faces,
+        })
+    }
+
+    fn face(&self, (x, y): (usize, usize)) -> Option<&Face> {
+        let origin = (x - x % self.size, y - y % self.size);
+
+        self.faces.iter().find(|face| face.origin == origin)
+    }
+}
+
+impl<'a> Navigate for CubeJungle<'a> {
+    fn get(&self, current: (usize, usize), dir: Dir) -> Option<&Tile> {
+        let (next, _) = self.pos(current, dir)?;
+
+        self.jungle.get(&next)
+    }
+
+    fn pos(&self, current: (usize, usize), dir: Dir) -> Option<((usize, usize), Dir)> {
+        let next = dir + current;
+
+        if self.jungle.get(&next).is_some() {
+            return Some((next, dir));
+        }
+
+        let from = self.face(current)?;
+        let towards = from.towards(dir);
+        let to = self.faces.iter().find(|face| face.normal == towards)?;
+
+        // over the edge, the tile is one step towards the next face, and one step down from the
+        // current one
+        let local = (current.0 - from.origin.0, current.1 - from.origin.1);
+        let center = from.center(local, self.size);
+        let center: Vec3 = [0, 1, 2].map(|k| center[k] + towards[k] - from.normal[k]);
+
+        let size = self.size as isize;
+        let (i, j) = (
+            (dot(center, to.x) + size - 1) / 2,
+            (dot(center, to.y) + size - 1) / 2,
+        );
+        let dir = Dir::iter().find(|&dir| to.towards(dir) == neg(from.normal))?;
+
+        Some(((to.origin.0 + i as usize, to.origin.1 + j as usize), dir))
+    }
+}
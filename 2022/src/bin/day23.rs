@@ -1,18 +1,21 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
-use itertools::Itertools;
-use std::collections::{HashMap, HashSet, VecDeque};
+use aoc_utils::collections::{Coord, SparseGrid};
+use std::collections::{HashMap, VecDeque};
 
 struct Day23;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
 struct Elf(i64, i64);
 
-impl From<(i64, i64)> for Elf {
-    fn from((x, y): (i64, i64)) -> Self {
+impl From<Coord> for Elf {
+    fn from((x, y): Coord) -> Self {
         Self(x, y)
     }
 }
 
+type Grove = SparseGrid<()>;
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Copy)]
 pub enum Direction {
     N,
@@ -51,22 +54,18 @@ impl From<Direction> for (i64, i64) {
 }
 
 impl Elf {
-    fn look_around(&self, grid: &HashSet<Elf>) -> HashMap<Direction, bool> {
+    fn look_around(&self, grid: &Grove) -> HashMap<Direction, bool> {
         Direction::iter()
             .map(|dir| {
                 let (dx, dy) = dir.into();
-                let next = (self.0 + dx, self.1 + dy).into();
+                let next = (self.0 + dx, self.1 + dy);
 
                 (dir, grid.contains(&next))
             })
             .collect()
     }
 
-    fn make_proposal(
-        &self,
-        grid: &HashSet<Elf>,
-        lookup: &VecDeque<[Direction; 3]>,
-    ) -> Option<(i64, i64)> {
+    fn make_proposal(&self, grid: &Grove, lookup: &VecDeque<[Direction; 3]>) -> Option<(i64, i64)> {
         let around = self.look_around(grid);
 
         if around.iter().all(|(_, &occupied)| !occupied) {
@@ -98,22 +97,14 @@ impl Day23 {
         ])
     }
 
-    fn get_area(input: &HashSet<Elf>) -> ((i64, i64), (i64, i64)) {
-        input.iter().fold(
-            match input.len() {
-                0 => ((0, 0), (0, 0)),
-                _ => ((i64::MAX, i64::MIN), (i64::MAX, i64::MIN)),
-            },
-            |(u, v), &Elf(x, y)| ((u.0.min(x), u.1.max(x + 1)), (v.0.min(y), v.1.max(y + 1))),
-        )
-    }
-
-    fn round(mut pool: HashSet<Elf>, directions: &VecDeque<[Direction; 3]>) -> HashSet<Elf> {
+    fn round(mut pool: Grove, directions: &VecDeque<[Direction; 3]>) -> Grove {
         let mut proposals: HashMap<_, Vec<_>> = HashMap::new();
 
-        for elf in &pool {
+        for &coord in pool.keys() {
+            let elf = Elf::from(coord);
+
             if let Some(proposal) = elf.make_proposal(&pool, directions) {
-                proposals.entry(proposal).or_default().push(*elf);
+                proposals.entry(proposal).or_default().push(elf);
             }
         }
 
@@ -123,36 +114,31 @@ impl Day23 {
                 1 => queue.first().map(|elf| (proposal, elf)),
                 _ => None,
             })
-            .for_each(|(&proposal, elf)| {
-                pool.remove(elf);
-                pool.insert(proposal.into());
+            .for_each(|(&proposal, &Elf(x, y))| {
+                pool.remove(&(x, y));
+                pool.insert(proposal, ());
             });
 
         pool
     }
 
-    fn print(input: &HashSet<Elf>, zoom: i64) {
-        let (x_range, y_range) = Self::get_area(input);
-
-        let grid = ((y_range.0 - zoom)..(y_range.1 + zoom))
-            .map(|y| {
-                ((x_range.0 - zoom)..(x_range.1 + zoom))
-                    .map(|x| match input.contains(&(x, y).into()) {
-                        true => "#",
-                        false => ".",
-                    })
-                    .collect::<String>()
-            })
-            .join("\n");
+    fn print(input: &Grove, zoom: i64) {
+        if let Some(bounds) = input.bounds() {
+            let grid = input.render_within(&bounds.pad(zoom), |_, elf| match elf {
+                Some(_) => '#',
+                None => '.',
+            });
 
-        println!("{}", grid);
+            println!("{}", grid);
+        }
     }
 }
 
 impl Solution for Day23 {
     const TITLE: &'static str = "Unstable Diffusion";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 23;
-    type Input = HashSet<Elf>;
+    type Input = Grove;
     type P1 = u64;
     type P2 = usize;
 
@@ -162,14 +148,14 @@ impl Solution for Day23 {
             .enumerate()
             .flat_map(|(y, row)| {
                 row.chars().enumerate().filter_map(move |(x, c)| match c {
-                    '#' => Some((x as i64, y as i64).into()),
+                    '#' => Some((x as i64, y as i64)),
                     _ => None,
                 })
             })
             .collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let mut directions = Day23::get_fov_list();
 
         is_print().then(|| Day23::print(input, 1));
@@ -185,27 +171,32 @@ impl Solution for Day23 {
             next_pool
         });
 
-        let (x_range, y_range) = Day23::get_area(&pool);
+        let area = pool
+            .bounds()
+            .map(|bounds| bounds.area())
+            .unwrap_or_default();
 
-        Some(x_range.1.abs_diff(x_range.0) * y_range.1.abs_diff(y_range.0) - pool.len() as u64)
+        Ok(area - pool.len() as u64)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let mut directions = Day23::get_fov_list();
         let mut pool = input.to_owned();
 
-        (1..).find(|_| {
-            let next_pool = Day23::round(pool.to_owned(), &directions);
+        (1..)
+            .find(|_| {
+                let next_pool = Day23::round(pool.to_owned(), &directions);
 
-            if next_pool == pool {
-                true
-            } else {
-                directions.rotate_left(1);
-                pool = next_pool;
+                if next_pool == pool {
+                    true
+                } else {
+                    directions.rotate_left(1);
+                    pool = next_pool;
 
-                false
-            }
-        })
+                    false
+                }
+            })
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -217,9 +208,7 @@ fn is_flag(flag: &str) -> bool {
     std::env::args().any(|arg| arg.as_str() == flag)
 }
 
-fn main() {
-    aoc::solution!(Day23);
-}
+aoc::run!(Day23);
 #[cfg(test)]
 mod tests {
     use crate::Day23 as day_23;
@@ -1,13 +1,15 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 
-use crate::valley::Valley;
+use self::valley::Valley;
 use aoc_utils::pathfinding::{bfs, Graph};
 
 struct Day24;
 
 mod valley {
     use aoc::solution::SolutionError;
-    use aoc_utils::{index, lcm};
+    use aoc_utils::index;
+    use aoc_utils::number::lcm;
     use itertools::Itertools;
     use std::collections::HashSet;
     use std::str::FromStr;
@@ -49,7 +51,7 @@ mod valley {
                 input.lines().count() - 2,
             );
 
-            let z_size = lcm(x_size, y_size);
+            let z_size = lcm(x_size, y_size).ok_or(SolutionError::ParseError)?;
 
             let valley = (0..z_size)
                 .map(|z| {
@@ -208,6 +210,7 @@ impl Graph<(i32, i32, i32)> for Expedition<'_> {
 
 impl Solution for Day24 {
     const TITLE: &'static str = "Blizzard Basin";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 24;
     type Input = Valley;
     type P1 = usize;
@@ -217,9 +220,9 @@ impl Solution for Day24 {
         input.parse()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let expedition = Expedition::new(input, input.entrance(), input.exit(), 0);
-        let ((_, _, time), path) = bfs::solve(&expedition)?;
+        let ((_, _, time), path) = bfs::solve(&expedition).ok_or(SolutionError::NoAnswer)?;
 
         is_print().then(|| {
             path().unwrap_or_default().iter().for_each(|(x, y, z)| {
@@ -231,24 +234,26 @@ impl Solution for Day24 {
             })
         });
 
-        Some(time as usize)
+        Ok(time as usize)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let start = input.entrance();
         let exit = input.exit();
 
-        (0..3).fold(Some(0), |time, count| {
-            let (start, exit) = match count % 2 == 0 {
-                true => (start, exit),
-                _ => (exit, start),
-            };
+        (0..3)
+            .fold(Some(0), |time, count| {
+                let (start, exit) = match count % 2 == 0 {
+                    true => (start, exit),
+                    _ => (exit, start),
+                };
 
-            let expedition = Expedition::new(input, start, exit, time? as i32);
-            let path = bfs::solve(&expedition);
+                let expedition = Expedition::new(input, start, exit, time? as i32);
+                let path = bfs::solve(&expedition);
 
-            path.map(|((_, _, time), _)| time as usize)
-        })
+                path.map(|((_, _, time), _)| time as usize)
+            })
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -260,9 +265,7 @@ fn is_flag(flag: &str) -> bool {
     std::env::args().any(|arg| arg.as_str() == flag)
 }
 
-fn main() {
-    aoc::solution!(Day24);
-}
+aoc::run!(Day24);
 
 #[cfg(test)]
 mod tests {
@@ -1,4 +1,4 @@
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_lines, SolutionError};
 use aoc::Solution;
 use itertools::Itertools;
 use std::fmt::{Display, Formatter};
@@ -74,29 +74,28 @@ impl Display for Snafu {
 
 impl Solution for Day25 {
     const TITLE: &'static str = "Full of Hot Air";
+    const YEAR: u16 = 2022;
     const DAY: u8 = 25;
     type Input = Vec<Snafu>;
     type P1 = Snafu;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input.lines().map(Snafu::from_str).collect()
+        parse_lines(input).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(Snafu::from(
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(Snafu::from(
             input.iter().map(|n| n.as_decimal()).sum::<isize>(),
         ))
     }
 
-    fn part2(_input: &Self::Input) -> Option<Self::P2> {
-        None
+    fn part2(_input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Err(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day25)
-}
+aoc::run!(Day25);
 #[cfg(test)]
 mod tests {
     use crate::Day25 as day_25;
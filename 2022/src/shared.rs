@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 use std::str::FromStr;
 
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_in, SolutionError};
 
 #[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
 pub struct Point<T>(T, T);
@@ -69,14 +69,25 @@ impl<T: Copy + Ord> Ord for Point<T> {
 impl<T: FromStr> FromStr for Point<T> {
     type Err = SolutionError;
 
+    /// Parses `x,y`, locating the invalid coordinate in the error.
+    ///
+    /// ```
+    /// # use shared::Point;
+    /// let point: Point<i32> = "3,-4".parse().unwrap();
+    /// assert_eq!(point, Point::new(3, -4));
+    ///
+    /// let err = "3,x".parse::<Point<i32>>().unwrap_err();
+    /// assert_eq!(
+    ///     err.diagnostic(),
+    ///     "expected i32, found 'x' at line 1, column 3\n  |\n1 | 3,x\n  |   ^"
+    /// );
+    /// ```
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        use SolutionError::*;
-
-        let (x, y) = value.split_once(',').ok_or(ParseError)?;
-        let x = x.parse().or(Err(ParseError))?;
-        let y = y.parse().or(Err(ParseError))?;
+        let (x, y) = value
+            .split_once(',')
+            .ok_or_else(|| SolutionError::expected(value, value, "a point 'x,y'"))?;
 
-        Ok(Self(x, y))
+        Ok(Self(parse_in(value, x)?, parse_in(value, y)?))
     }
 }
 
@@ -137,3 +148,8 @@ pub mod geometry {
         }
     }
 }
+
+extern crate self as shared;
+
+// Every day of the year, registered for the `aoc` binary, see `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use itertools::Itertools;
 
@@ -32,6 +33,7 @@ impl Day01 {
 
 impl Solution for Day01 {
     const TITLE: &'static str = "Trebuchet?!";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 1;
     type Input = Vec<String>;
     type P1 = usize;
@@ -41,7 +43,7 @@ impl Solution for Day01 {
         Ok(input.lines().map(|line| line.to_owned()).collect())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .iter()
             .map(|input| {
@@ -52,9 +54,10 @@ impl Solution for Day01 {
             })
             .filter_map(|input| Day01::list_to_number(&input))
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let mapping: Vec<_> =
             s! { zero one two three four five six seven eight nine 0 1 2 3 4 5 6 7 8 9 };
 
@@ -62,12 +65,11 @@ impl Solution for Day01 {
             .iter()
             .filter_map(|input| Day01::solve(input, &mapping))
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day01)
-}
+aoc::run!(Day01);
 #[cfg(test)]
 mod tests {
     use crate::Day01 as day_01;
@@ -56,6 +56,7 @@ struct Day02;
 
 impl Solution for Day02 {
     const TITLE: &'static str = "Cube Conundrum";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 2;
     type Input = Vec<Game>;
     type P1 = u32;
@@ -65,7 +66,7 @@ impl Solution for Day02 {
         input.lines().map(|line| line.parse()).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .iter()
             .filter(|game| {
@@ -77,9 +78,10 @@ impl Solution for Day02 {
             })
             .map(|game| game.id)
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         input
             .iter()
             .map(|game| {
@@ -95,12 +97,11 @@ impl Solution for Day02 {
                 r * g * b
             })
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day02)
-}
+aoc::run!(Day02);
 #[cfg(test)]
 mod tests {
     use crate::Day02 as day_02;
@@ -78,6 +78,7 @@ impl FromStr for Schematic {
 
 impl Solution for Day03 {
     const TITLE: &'static str = "Gear Ratios";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 3;
     type Input = Schematic;
     type P1 = u32;
@@ -87,15 +88,16 @@ impl Solution for Day03 {
         input.parse()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .symbols
             .iter()
             .flat_map(|symbol| input.get_parts(symbol))
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         input
             .symbols
             .iter()
@@ -109,12 +111,11 @@ impl Solution for Day03 {
                 }
             })
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day03)
-}
+aoc::run!(Day03);
 #[cfg(test)]
 mod tests {
     use crate::Day03 as day_03;
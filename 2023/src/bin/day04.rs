@@ -1,4 +1,4 @@
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_lines, SolutionError};
 use aoc::Solution;
 use itertools::Itertools;
 use std::str::FromStr;
@@ -40,16 +40,17 @@ impl FromStr for ScratchCard {
 
 impl Solution for Day04 {
     const TITLE: &'static str = "Scratchcards";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 4;
     type Input = Vec<ScratchCard>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input.lines().map(ScratchCard::from_str).collect()
+        parse_lines(input).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .iter()
             .map(ScratchCard::winning_numbers)
@@ -59,9 +60,10 @@ impl Solution for Day04 {
             })
             .map(|game| 2_usize.pow(game as u32))
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let initial = vec![1; input.len()];
 
         input
@@ -77,12 +79,11 @@ impl Solution for Day04 {
             })
             .iter()
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
-fn main() {
-    aoc::solution!(Day04)
-}
+aoc::run!(Day04);
 #[cfg(test)]
 mod tests {
     use crate::Day04 as day_04;
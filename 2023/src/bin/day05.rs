@@ -1,5 +1,7 @@
 use aoc::solution::SolutionError;
 use aoc::Solution;
+use aoc_utils::interval::{IntervalSet, RangeMap};
+use aoc_utils::paragraphs;
 use itertools::Itertools;
 use std::str::FromStr;
 
@@ -9,45 +11,14 @@ type MapFormat = (i64, i64, i64);
 #[derive(Debug)]
 struct Almanac {
     seeds: Vec<i64>,
-    maps: Vec<Vec<MapFormat>>,
+    maps: Vec<RangeMap<i64>>,
 }
 
 impl Almanac {
-    fn solve(&self, origin: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    fn solve(&self, origin: IntervalSet<i64>) -> IntervalSet<i64> {
         self.maps
             .iter()
-            .fold(origin.to_owned(), |mut seeds, ranges| {
-                let mut result = vec![];
-
-                while let Some((seed_start, end)) = seeds.pop() {
-                    if let Some((overlap_range, offset)) =
-                        ranges.iter().find_map(|(dest, src, size)| {
-                            let start = seed_start.max(*src);
-                            let end = end.min(src + size);
-
-                            (start < end).then_some(((start, end), dest - src))
-                        })
-                    {
-                        let (overlap_start, overlap_end) = overlap_range;
-
-                        result.push((overlap_start + offset, overlap_end + offset));
-
-                        //check for unmapped ranges
-                        {
-                            if overlap_start > seed_start {
-                                seeds.push((seed_start, overlap_start));
-                            }
-
-                            if end > overlap_end {
-                                seeds.push((overlap_end, end));
-                            }
-                        }
-                    } else {
-                        result.push((seed_start, end));
-                    }
-                }
-                result
-            })
+            .fold(origin, |seeds, map| map.apply(&seeds))
     }
 }
 
@@ -55,8 +26,7 @@ impl FromStr for Almanac {
     type Err = SolutionError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let input = input.replace('\r', "");
-        let mut it = input.split("\n\n");
+        let mut it = paragraphs(input);
 
         let seeds = it
             .next()
@@ -76,7 +46,8 @@ impl FromStr for Almanac {
                             .filter_map(|num| num.parse().ok())
                             .collect_tuple::<MapFormat>()
                     })
-                    .collect_vec()
+                    .map(|(dest, src, size)| (src..src + size, dest))
+                    .collect()
             })
             .collect_vec();
 
@@ -86,6 +57,7 @@ impl FromStr for Almanac {
 
 impl Solution for Day05 {
     const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 5;
     type Input = Almanac;
     type P1 = i64;
@@ -95,35 +67,25 @@ impl Solution for Day05 {
         input.parse()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
-            .solve(
-                &input
-                    .seeds
-                    .iter()
-                    .map(|start| (*start, *start + 1))
-                    .collect_vec(),
-            )
-            .into_iter()
-            .sorted()
+            .solve(input.seeds.iter().map(|start| *start..*start + 1).collect())
             .min()
-            .map(|(start, _)| start)
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         input
             .solve(
-                &input
+                input
                     .seeds
                     .iter()
                     .tuples()
-                    .map(|(start, size)| (*start, start + size))
-                    .collect_vec(),
+                    .map(|(start, size)| *start..start + size)
+                    .collect(),
             )
-            .into_iter()
-            .sorted()
             .min()
-            .map(|(start, _)| start)
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -41,6 +41,7 @@ impl FromStr for Puzzle {
 
 impl Solution for Day06 {
     const TITLE: &'static str = "Wait For It";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 6;
     type Input = Puzzle;
     type P1 = usize;
@@ -50,20 +51,31 @@ impl Solution for Day06 {
         Puzzle::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .0
             .iter()
             .map(|(time, distance)| Puzzle::get_bounds(*time, *distance))
-            .product()
+            .product::<Option<_>>()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let (time, distance): (Vec<_>, Vec<_>) = input.0.iter().cloned().unzip();
-        let time = time.iter().join("").parse::<usize>().ok()?;
-        let distance = distance.iter().join("").parse::<usize>().ok()?;
+        let time = time
+            .iter()
+            .join("")
+            .parse::<usize>()
+            .ok()
+            .ok_or(SolutionError::NoAnswer)?;
+        let distance = distance
+            .iter()
+            .join("")
+            .parse::<usize>()
+            .ok()
+            .ok_or(SolutionError::NoAnswer)?;
 
-        Puzzle::get_bounds(time, distance)
+        Puzzle::get_bounds(time, distance).ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -1,4 +1,4 @@
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_lines, SolutionError};
 use aoc::Solution;
 use itertools::Itertools;
 use std::collections::HashMap;
@@ -132,25 +132,26 @@ struct Day07;
 
 impl Solution for Day07 {
     const TITLE: &'static str = "Camel Cards";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 7;
     type Input = Vec<Game>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input.lines().map(Game::from_str).collect()
+        parse_lines(input).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let camel_card = CamelCard::new("23456789TJQKA", None);
 
-        camel_card.play(input)
+        camel_card.play(input).ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let camel_card = CamelCard::new("J23456789TQKA", Some('J'));
 
-        camel_card.play(input)
+        camel_card.play(input).ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -1,6 +1,7 @@
 use aoc::solution::SolutionError;
 use aoc::Solution;
-use aoc_utils::lcm;
+use aoc_utils::number::lcm_all;
+use aoc_utils::paragraphs;
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -47,9 +48,9 @@ impl FromStr for GhostMap {
     type Err = SolutionError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let input = input.replace('\r', "");
-
-        let (directions, map) = input.split_once("\n\n").ok_or(SolutionError::ParseError)?;
+        let (directions, map) = paragraphs(input)
+            .collect_tuple()
+            .ok_or(SolutionError::ParseError)?;
 
         let map = map
             .lines()
@@ -77,6 +78,7 @@ struct Day08;
 
 impl Solution for Day08 {
     const TITLE: &'static str = "Haunted Wasteland";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 8;
     type Input = GhostMap;
     type P1 = usize;
@@ -86,26 +88,30 @@ impl Solution for Day08 {
         GhostMap::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let position = "AAA".to_owned();
 
         if !input.map.contains_key(&position) {
-            return None;
+            return Err(SolutionError::NoAnswer);
         }
 
-        input.find_exit(position, |exit| exit == "ZZZ")
+        input
+            .find_exit(position, |exit| exit == "ZZZ")
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let positions = input
             .map
             .keys()
             .filter(|key| key.ends_with('A'))
             .cloned()
             .map(|position| input.find_exit(position, |exit| exit.ends_with('Z')))
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<Option<Vec<_>>>()
+            .filter(|positions| !positions.is_empty())
+            .ok_or(SolutionError::NoAnswer)?;
 
-        positions.iter().cloned().reduce(lcm)
+        lcm_all(positions).ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -60,6 +60,7 @@ struct Day09;
 
 impl Solution for Day09 {
     const TITLE: &'static str = "Mirage Maintenance";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 9;
     type Input = Oasis;
     type P1 = i32;
@@ -69,12 +70,22 @@ impl Solution for Day09 {
         Oasis::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        input.0.iter().map(|hist| hist.last()).sum()
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        input
+            .0
+            .iter()
+            .map(|hist| hist.last())
+            .sum::<Option<_>>()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        input.0.iter().map(|hist| hist.first()).sum()
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        input
+            .0
+            .iter()
+            .map(|hist| hist.first())
+            .sum::<Option<_>>()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -1,15 +1,16 @@
 use aoc::solution::SolutionError;
 use aoc::Solution;
-use aoc_utils::pathfinding::Graph;
+use aoc_utils::pathfinding::{bfs, Graph};
+use aoc_utils::polygon::Polygon;
 use itertools::Itertools;
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
 use std::env::args;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 struct Day10;
 
-type History = HashMap<(usize, usize), Option<(usize, usize)>>;
+type History = HashMap<(usize, usize), usize>;
 
 struct Maze {
     raw: Vec<Vec<char>>,
@@ -34,26 +35,30 @@ impl Maze {
     }
 
     fn solve(&self) -> Option<History> {
-        let mut history = HashMap::new();
-        let mut queue = VecDeque::new();
+        self.start()?;
 
-        let start = self.start()?;
-
-        queue.push_back(start);
-        history.insert(start, None);
-
-        while let Some(current) = queue.pop_front() {
-            for next in self.adjacent(&current)? {
-                if history.contains_key(&next) {
-                    continue;
-                }
+        Some(bfs::distances(self, None))
+    }
 
-                history.insert(next, Some(current));
-                queue.push_back(next)
-            }
+    /// Tiles of the loop in order, from the start.
+    fn tube_path(&self) -> Option<Vec<(usize, usize)>> {
+        let start = self.start()?;
+        let mut path = vec![start];
+        let mut current = *self.adjacent(&start)?.first()?;
+
+        while current != start {
+            let previous = path[path.len() - 1];
+
+            path.push(current);
+            // the start's neighbors don't connect back to it, the loop closes there
+            current = self
+                .adjacent(&current)?
+                .into_iter()
+                .find(|next| *next != previous)
+                .unwrap_or(start);
         }
 
-        Some(history)
+        Some(path)
     }
 }
 
@@ -164,6 +169,7 @@ impl Graph<(usize, usize)> for Maze {
 
 impl Solution for Day10 {
     const TITLE: &'static str = "Pipe Maze";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 10;
     type Input = Maze;
     type P1 = usize;
@@ -173,49 +179,46 @@ impl Solution for Day10 {
         Maze::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(input.tube()?.len() / 2)
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        input
+            .tube()
+            .ok_or(SolutionError::NoAnswer)?
+            .values()
+            .max()
+            .copied()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        let hist = input.tube()?;
-        let width = input.raw.first()?.len();
-        let height = input.raw.len();
-
-        (0..height)
-            .map(|y| {
-                (0..width)
-                    .fold((false, 0), |(is_inside, count), x| {
-                        if hist.contains_key(&(x, y)) {
-                            //Might not work on other inputs.
-                            //Need to count S if it's a J,L ior |. (it's a 7 in my input, so it works)
-                            match input.get(&(x, y)) {
-                                Some('J') | Some('L') | Some('|') => (!is_inside, count),
-                                _ => (is_inside, count),
-                            }
-                        } else {
-                            (is_inside, count + is_inside as usize)
-                        }
-                    })
-                    .1
-            })
-            .sum1()
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        let tube: Polygon<i64> = input
+            .tube_path()
+            .ok_or(SolutionError::NoAnswer)?
+            .into_iter()
+            .map(|(x, y)| (x as i64, y as i64))
+            .collect();
+
+        Ok(tube.interior_points() as usize)
     }
 }
 
 fn main() {
     if args().any(|arg| arg == "--print") {
-        println!("{}", Day10::parse(&Day10::get_input().unwrap()).unwrap())
+        println!("{}", Day10::parse(&aoc::input!(Day10).unwrap()).unwrap())
     } else {
         aoc::solution!(Day10)
     }
 }
 
+aoc::register!(Day10);
+
 aoc::example! {
     [Day10]
     smallest: ".....\r\n.S-7.\r\n.|.|.\r\n.L-J.\r\n.....\r\n"
         => Some(4)
         => Some(1)
+    vertical_start: ".....\r\n.F-7.\r\n.S.|.\r\n.L-J.\r\n.....\r\n"
+        => Some(4)
+        => Some(1)
     smallest_noise: "-L|F7\r\n7S-7|\r\nL|7||\r\n-L-J|\r\nL|-JF\r\n"
         => Some(4)
         => Some(1)
@@ -97,6 +97,7 @@ struct Day11;
 
 impl Solution for Day11 {
     const TITLE: &'static str = "Cosmic Expansion";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 11;
     type Input = Cosmos;
     type P1 = usize;
@@ -106,22 +107,18 @@ impl Solution for Day11 {
         Cosmos::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(
-            input
-                .galaxy_pairs()
-                .filter_map(|(lhs, rhs)| input.distance::<1>(lhs, rhs))
-                .sum(),
-        )
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(input
+            .galaxy_pairs()
+            .filter_map(|(lhs, rhs)| input.distance::<1>(lhs, rhs))
+            .sum())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        Some(
-            input
-                .galaxy_pairs()
-                .filter_map(|(lhs, rhs)| input.distance::<1000000>(lhs, rhs))
-                .sum(),
-        )
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Ok(input
+            .galaxy_pairs()
+            .filter_map(|(lhs, rhs)| input.distance::<1000000>(lhs, rhs))
+            .sum())
     }
 }
 
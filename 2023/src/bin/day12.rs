@@ -78,6 +78,7 @@ struct Day12;
 
 impl Solution for Day12 {
     const TITLE: &'static str = "Hot Springs";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 12;
     type Input = SpringRecord;
     type P1 = usize;
@@ -87,7 +88,7 @@ impl Solution for Day12 {
         SpringRecord::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .0
             .iter()
@@ -98,22 +99,21 @@ impl Solution for Day12 {
                 )
             })
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        Some(
-            input
-                .0
-                .par_iter()
-                .map(|(record, val)| {
-                    (
-                        Pointer::from_iter((0..5).map(|_| record).join("?").chars()),
-                        Pointer::from_iter((0..5).flat_map(|_| val).copied()),
-                    )
-                })
-                .map(|(record, val)| solve(record, val))
-                .sum(),
-        )
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Ok(input
+            .0
+            .par_iter()
+            .map(|(record, val)| {
+                (
+                    Pointer::from_iter((0..5).map(|_| record).join("?").chars()),
+                    Pointer::from_iter((0..5).flat_map(|_| val).copied()),
+                )
+            })
+            .map(|(record, val)| solve(record, val))
+            .sum())
     }
 }
 
@@ -1,5 +1,6 @@
 use aoc::solution::SolutionError;
 use aoc::Solution;
+use aoc_utils::paragraphs;
 use itertools::Itertools;
 use std::fmt::Debug;
 use std::str::FromStr;
@@ -54,20 +55,17 @@ impl FromStr for Image {
 
 impl Solution for Day13 {
     const TITLE: &'static str = "Point of Incidence";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 13;
     type Input = Vec<Image>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input
-            .replace('\r', "")
-            .split("\n\n")
-            .map(Image::from_str)
-            .collect()
+        paragraphs(input).map(Image::from_str).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .iter()
             .map(|img| {
@@ -75,9 +73,10 @@ impl Solution for Day13 {
                     + img.rotate().find_mirror(0).unwrap_or_default()
             })
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         input
             .iter()
             .map(|img| {
@@ -85,6 +84,7 @@ impl Solution for Day13 {
                     + img.rotate().find_mirror(1).unwrap_or_default()
             })
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -83,6 +83,7 @@ impl FromStr for Platform {
 
 impl Solution for Day14 {
     const TITLE: &'static str = "Parabolic Reflector Dish";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 14;
     type Input = Platform;
     type P1 = usize;
@@ -92,36 +93,38 @@ impl Solution for Day14 {
         Platform::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let mut platform = input.clone();
 
         platform.tilt('N');
 
-        Some(platform.load())
+        Ok(platform.load())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         const CYCLES: usize = 1_000_000_000;
         let mut loads = vec![];
         let mut platform = input.to_owned();
         let mut cache = HashMap::new();
 
-        (0..).find_map(|cycle| {
-            for tilt in ['N', 'W', 'S', 'E'] {
-                platform.tilt(tilt);
-            }
+        (0..)
+            .find_map(|cycle| {
+                for tilt in ['N', 'W', 'S', 'E'] {
+                    platform.tilt(tilt);
+                }
 
-            loads.push(platform.load());
+                loads.push(platform.load());
 
-            if let Some(offset) = cache.insert(platform.to_string(), cycle) {
-                let size = cycle - offset;
-                let target = (CYCLES - offset) % size + offset - 1;
+                if let Some(offset) = cache.insert(platform.to_string(), cycle) {
+                    let size = cycle - offset;
+                    let target = (CYCLES - offset) % size + offset - 1;
 
-                return Some(loads[target]);
-            }
+                    return Some(loads[target]);
+                }
 
-            None
-        })
+                None
+            })
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -1,3 +1,4 @@
+use aoc::solution::SolutionError;
 use aoc::{example, Solution};
 use itertools::Itertools;
 
@@ -17,6 +18,7 @@ fn hash(input: &str) -> u16 {
 
 impl Solution for Day15 {
     const TITLE: &'static str = "Lens Library";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 15;
     type Input = Vec<String>;
     type P1 = usize;
@@ -26,11 +28,15 @@ impl Solution for Day15 {
         Ok(input.trim().split(',').map(|c| c.to_owned()).collect_vec())
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        input.iter().map(|code| hash(code) as usize).sum1()
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        input
+            .iter()
+            .map(|code| hash(code) as usize)
+            .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         const BOX: Vec<(String, usize)> = vec![];
 
         input
@@ -79,6 +85,7 @@ impl Solution for Day15 {
                     })
                     .sum()
             })
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -1,8 +1,9 @@
+use aoc::solution::SolutionError;
 use aoc::Solution;
 use aoc_utils::collections::Matrix;
 use itertools::Itertools;
 use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 struct Day16;
 
@@ -15,7 +16,7 @@ impl Day16 {
     }
 
     fn cast_beam(
-        input: &HashMap<(usize, usize), char>,
+        input: &Matrix<char>,
         start: (usize, usize),
         start_dir: (isize, isize),
     ) -> HashSet<((usize, usize), (isize, isize))> {
@@ -52,34 +53,38 @@ impl Day16 {
 
 impl Solution for Day16 {
     const TITLE: &'static str = "The Floor Will Be Lava";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 16;
     type Input = Matrix<char>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        Ok(input.into())
+        Matrix::try_from_str(input).map_err(|err| {
+            let row = input.lines().nth(err.row).unwrap_or(input);
+
+            SolutionError::expected(input, row, &format!("{} tiles", err.width))
+        })
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        let matrix: HashMap<_, _> = input.iter().cloned().collect();
-        let history = Day16::cast_beam(&matrix, (0, 0), (1, 0));
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        let history = Day16::cast_beam(input, (0, 0), (1, 0));
 
-        Some(history.iter().unique_by(|(b, _)| b).count())
+        Ok(history.iter().unique_by(|(b, _)| b).count())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let width = input.width();
         let height = input.height();
-        let matrix: HashMap<_, _> = input.iter().cloned().collect();
 
         (0..width)
             .flat_map(|x| [((x, 0), (0, 1)), ((x, height - 1), (0, -1))])
             .chain((0..height).flat_map(|y| [((0, y), (1, 0)), ((width - 1, y), (-1, 0))]))
             .par_bridge()
-            .map(|(start, dir)| Day16::cast_beam(&matrix, start, dir))
+            .map(|(start, dir)| Day16::cast_beam(input, start, dir))
             .map(|hist| hist.iter().unique_by(|(b, _)| b).count())
             .max()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -1,6 +1,6 @@
 use aoc::solution::SolutionError;
 use aoc::Solution;
-use aoc_utils::pathfinding::Graph;
+use aoc_utils::pathfinding::{a_star, Graph};
 use std::env::args;
 use std::str::FromStr;
 
@@ -39,7 +39,10 @@ impl Maze<'_> {
     }
 
     fn solve(&self) -> Option<i32> {
-        let (c, get_path) = aoc_utils::pathfinding::dijkstra::solve(self, self.get_cost_fn())?;
+        let (width, height) = (self.0.width(), self.0.height());
+        let (c, get_path) = a_star::solve(self, self.get_cost_fn(), |((x, y), _, _)| {
+            (width - 1 - x + height - 1 - y) as i32
+        })?;
 
         print().then(|| {
             if let Some(path) = get_path() {
@@ -133,6 +136,7 @@ impl Graph<((usize, usize), (isize, isize), i8)> for Maze<'_> {
 
 impl Solution for Day17 {
     const TITLE: &'static str = "Clumsy Crucible";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 17;
     type Input = City;
     type P1 = i32;
@@ -142,16 +146,16 @@ impl Solution for Day17 {
         City::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let maze = Maze(input, Crucible(0, 3));
 
-        maze.solve()
+        maze.solve().ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let maze = Maze(input, Crucible(4, 10));
 
-        maze.solve()
+        maze.solve().ok_or(SolutionError::NoAnswer)
     }
 }
 
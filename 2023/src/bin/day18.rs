@@ -1,11 +1,12 @@
 use std::str::FromStr;
 
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_lines, SolutionError};
 use aoc::Solution;
+use aoc_utils::polygon::Polygon;
 use itertools::Itertools;
 
-use crate::canvas::{Line, Point};
-use crate::viz::print;
+use self::canvas::{Line, Point};
+use self::viz::print;
 
 mod viz {
     use std::env::args;
@@ -13,8 +14,8 @@ mod viz {
 
     use itertools::Itertools;
 
-    use crate::canvas::{Line, Point};
-    use crate::DigSite;
+    use super::canvas::{Line, Point};
+    use super::DigSite;
 
     pub fn print() -> bool {
         args().any(|c| c.contains("--print"))
@@ -103,12 +104,6 @@ mod canvas {
         pub fn new(start: Point, end: Point) -> Self {
             Self(start, end)
         }
-        pub fn dist(&self) -> usize {
-            let Line(Point(x0, y0), Point(x1, y1)) = self;
-
-            (x1.abs_diff(*x0)) + (y1.abs_diff(*y0))
-        }
-
         pub fn start(&self) -> &Point {
             &self.0
         }
@@ -174,18 +169,17 @@ impl DigSite {
         )
     }
 
-    fn border_size(&self) -> usize {
-        self.0.iter().map(|line| line.dist()).sum()
-    }
-
+    /// Cubic meters of the trench and the lagoon it encloses.
     fn area(&self) -> Option<usize> {
-        self.0
+        let outline: Polygon<isize> = self
+            .0
             .iter()
-            .map(|line| line.start().x() * line.end().y() - line.end().x() * line.start().y())
-            .sum1::<isize>()
-            .map(|area| area.unsigned_abs())
-            .map(|area| area + self.border_size())
-            .map(|area| area / 2 + 1)
+            .map(|line| (line.start().x(), line.start().y()))
+            .collect();
+
+        (!self.0.is_empty())
+            .then(|| outline.interior_points() + outline.boundary_points())
+            .map(isize::unsigned_abs)
     }
 }
 
@@ -209,27 +203,30 @@ impl FromStr for Instruction {
 
 impl Solution for Day18 {
     const TITLE: &'static str = "Lavaduct Lagoon";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 18;
     type Input = Vec<Instruction>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input.lines().map(Instruction::from_str).collect()
+        parse_lines(input).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let dig_site = DigSite::new(&input.iter().map(Instruction::by_values).collect_vec());
 
         if print() {
             dig_site.print();
         }
 
-        dig_site.area()
+        dig_site.area().ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        DigSite::new(&input.iter().filter_map(Instruction::by_color).collect_vec()).area()
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        DigSite::new(&input.iter().filter_map(Instruction::by_color).collect_vec())
+            .area()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -3,30 +3,38 @@ use std::str::FromStr;
 
 use aoc::solution::SolutionError;
 use aoc::Solution;
+use aoc_utils::cuboid::Cuboid;
+use aoc_utils::paragraphs;
 use itertools::Itertools;
 
 struct Day19;
 
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+/// Axis of a category in the ratings of a part, and in the boxes of ratings.
+fn axis(category: &str) -> Option<usize> {
+    CATEGORIES.iter().position(|&c| c == category)
+}
+
 #[derive(Debug)]
 struct Workshop {
     workflows: HashMap<String, Vec<Operation>>,
-    parts: Vec<HashMap<char, usize>>,
+    parts: Vec<[usize; 4]>,
 }
 
+/// Comparisons hold the [`axis`] of their category.
 #[derive(Debug)]
 enum Operation {
-    GT(char, usize, String),
-    LT(char, usize, String),
+    GT(usize, usize, String),
+    LT(usize, usize, String),
     Move(String),
 }
 
 impl Operation {
-    fn validate(&self, parts: &HashMap<char, usize>) -> Option<&str> {
+    fn validate(&self, parts: &[usize; 4]) -> Option<&str> {
         match self {
-            Operation::GT(part, count, dest) => {
-                (parts.get(part) > Some(count)).then_some(dest.as_str())
-            }
-            Operation::LT(part, count, dest) => (parts.get(part) < Some(count)).then_some(dest),
+            Operation::GT(axis, count, dest) => (parts[*axis] > *count).then_some(dest.as_str()),
+            Operation::LT(axis, count, dest) => (parts[*axis] < *count).then_some(dest),
             Operation::Move(dest) => Some(dest),
         }
     }
@@ -44,11 +52,11 @@ impl FromStr for Operation {
             let count = count.parse().map_err(|_| SolutionError::ParseError)?;
 
             let (name, cond) = name_cond.split_at(name_cond.len() - 1);
-            let name = name.chars().next().ok_or(SolutionError::ParseError)?;
+            let axis = axis(name).ok_or(SolutionError::ParseError)?;
 
             Ok(match cond {
-                ">" => Operation::GT(name.to_owned(), count, redir.to_owned()),
-                "<" => Operation::LT(name.to_owned(), count, redir.to_owned()),
+                ">" => Operation::GT(axis, count, redir.to_owned()),
+                "<" => Operation::LT(axis, count, redir.to_owned()),
                 _ => return Err(SolutionError::ParseError),
             })
         } else {
@@ -61,9 +69,9 @@ impl FromStr for Workshop {
     type Err = SolutionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let input = s.replace('\r', "");
-
-        let (workflows, parts) = input.split_once("\n\n").ok_or(SolutionError::ParseError)?;
+        let (workflows, parts) = paragraphs(s)
+            .collect_tuple()
+            .ok_or(SolutionError::ParseError)?;
 
         let workflows = workflows
             .lines()
@@ -87,13 +95,12 @@ impl FromStr for Workshop {
                 let line = line.replace(['{', '}'], "");
 
                 line.split(',')
-                    .map(|part_count| {
+                    .try_fold([0; 4], |mut ratings, part_count| {
                         let (part, count) = part_count.split_once('=')?;
-                        let part = part.chars().next()?;
 
-                        Some((part, count.parse::<usize>().ok()?))
+                        ratings[axis(part)?] = count.parse().ok()?;
+                        Some(ratings)
                     })
-                    .collect::<Option<HashMap<_, _>>>()
                     .ok_or(SolutionError::ParseError)
             })
             .collect::<Result<Vec<_>, SolutionError>>()?;
@@ -103,7 +110,7 @@ impl FromStr for Workshop {
 }
 
 impl Workshop {
-    fn accepted_parts(&self) -> impl Iterator<Item = &HashMap<char, usize>> {
+    fn accepted_parts(&self) -> impl Iterator<Item = &[usize; 4]> {
         self.parts.iter().filter(|parts| {
             let mut current = "in";
 
@@ -121,43 +128,39 @@ impl Workshop {
         })
     }
 
-    fn accepted_ranges(
-        &self,
-        start: HashMap<char, (usize, usize)>,
-    ) -> Vec<HashMap<char, (usize, usize)>> {
+    fn accepted_ranges(&self, start: Cuboid<usize, 4>) -> Vec<Cuboid<usize, 4>> {
         let mut queue = VecDeque::new();
         let mut results = Vec::new();
 
         queue.push_back((start, "in"));
 
-        while let Some((mut current_parts, name)) = queue.pop_front() {
-            if let Some(operations) = self.workflows.get(name) {
-                for op in operations {
-                    let (next_part, next) = match op {
-                        Operation::GT(part, count, dest) => {
-                            let mut valid_parts = current_parts.to_owned();
-
-                            valid_parts.get_mut(part).unwrap().0 = count + 1;
-                            current_parts.get_mut(part).unwrap().1 = *count;
-
-                            (valid_parts, dest)
-                        }
-                        Operation::LT(part, count, dest) => {
-                            let mut valid_parts = current_parts.to_owned();
-
-                            valid_parts.get_mut(part).unwrap().1 = count - 1;
-                            current_parts.get_mut(part).unwrap().0 = *count;
-
-                            (valid_parts, dest)
-                        }
-                        Operation::Move(dest) => (current_parts.to_owned(), dest),
-                    };
-
-                    match next.as_str() {
-                        "A" => results.push(next_part),
-                        "R" => {}
-                        _ => queue.push_back((next_part, next)),
+        while let Some((parts, name)) = queue.pop_front() {
+            let mut current_parts = Some(parts);
+
+            for op in self.workflows.get(name).into_iter().flatten() {
+                let Some(current) = current_parts else {
+                    break;
+                };
+                let (next_part, next) = match op {
+                    Operation::GT(axis, count, dest) => {
+                        let (rest, valid) = current.split(*axis, count + 1);
+
+                        current_parts = rest;
+                        (valid, dest)
+                    }
+                    Operation::LT(axis, count, dest) => {
+                        let (valid, rest) = current.split(*axis, *count);
+
+                        current_parts = rest;
+                        (valid, dest)
                     }
+                    Operation::Move(dest) => (Some(current), dest),
+                };
+
+                match (next_part, next.as_str()) {
+                    (None, _) | (_, "R") => {}
+                    (Some(next_part), "A") => results.push(next_part),
+                    (Some(next_part), _) => queue.push_back((next_part, next)),
                 }
             }
         }
@@ -167,6 +170,7 @@ impl Workshop {
 
 impl Solution for Day19 {
     const TITLE: &'static str = "Aplenty";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 19;
     type Input = Workshop;
     type P1 = usize;
@@ -176,26 +180,21 @@ impl Solution for Day19 {
         Workshop::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         input
             .accepted_parts()
-            .map(|parts| parts.values().sum::<usize>())
+            .map(|parts| parts.iter().sum::<usize>())
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        let start: HashMap<_, _> = "xmas".chars().map(|c| (c, (1, 4000))).collect();
-
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         input
-            .accepted_ranges(start)
+            .accepted_ranges(Cuboid::inclusive([1; 4], [4000; 4]))
             .iter()
-            .map(|parts| {
-                parts
-                    .values()
-                    .map(|(start, end)| end - start + 1)
-                    .product::<usize>()
-            })
+            .map(Cuboid::volume)
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -6,7 +6,7 @@ use aoc::solution::SolutionError;
 use aoc::Solution;
 use itertools::Itertools;
 
-use aoc_utils::lcm;
+use aoc_utils::number::lcm_all;
 
 #[derive(Debug, Clone)]
 enum Module {
@@ -138,6 +138,7 @@ struct Day20;
 
 impl Solution for Day20 {
     const TITLE: &'static str = "Pulse Propagation";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 20;
     type Input = CommandCenter;
     type P1 = usize;
@@ -147,7 +148,7 @@ impl Solution for Day20 {
         CommandCenter::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let mut command_center = input.to_owned();
 
         (0..1000)
@@ -167,16 +168,18 @@ impl Solution for Day20 {
             })
             .values()
             .product1()
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let mut command_center = input.to_owned();
 
         let (last_module_source, (module, _)) = input
             .iter()
             .filter(|(_, (_, subs))| subs.contains(&"rx".to_owned()))
             .exactly_one()
-            .ok()?;
+            .ok()
+            .ok_or(SolutionError::NoAnswer)?;
 
         let mut target_modules = match module {
             Module::Conjunction(state) => Some(
@@ -186,7 +189,9 @@ impl Solution for Day20 {
                     .collect::<HashMap<_, _>>(),
             ),
             _ => None,
-        }?;
+        }
+        .filter(|modules| !modules.is_empty())
+        .ok_or(SolutionError::NoAnswer)?;
 
         (1..).find(|index| {
             command_center.push_button(|source, dest, pulse| {
@@ -202,7 +207,7 @@ impl Solution for Day20 {
             target_modules.values().all(|count| count > &0)
         });
 
-        target_modules.values().cloned().reduce(lcm)
+        lcm_all(target_modules.values().cloned()).ok_or(SolutionError::NoAnswer)
     }
 }
 
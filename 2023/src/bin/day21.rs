@@ -1,4 +1,4 @@
-use std::collections::{HashSet, VecDeque};
+use std::collections::HashSet;
 use std::str::FromStr;
 
 use aoc::solution::SolutionError;
@@ -6,6 +6,7 @@ use aoc::Solution;
 use itertools::Itertools;
 
 use aoc_utils::is_flag;
+use aoc_utils::pathfinding::{bfs, Graph};
 
 struct Day21<const STEPS: usize>;
 
@@ -40,42 +41,39 @@ impl Garden {
 
 impl Garden {
     fn solve(&self, start: (usize, usize), max_steps: usize) -> HashSet<(usize, usize)> {
-        let mut visited = HashSet::from([start]);
-        let mut accepted = HashSet::new();
-
-        let mut queue = VecDeque::from([(start, max_steps)]);
-
-        while let Some((current, steps)) = queue.pop_front() {
-            if steps % 2 == 0 {
-                accepted.insert(current);
-            }
-
-            if steps > 0 {
-                for next in self.adjacent(&current) {
-                    if visited.insert(next) {
-                        queue.push_back((next, steps - 1));
-                    }
-                }
-            }
-        }
-
-        accepted
+        bfs::distances_from(self, [start], Some(max_steps))
+            .into_iter()
+            .filter(|(_, steps)| (max_steps - steps).is_multiple_of(2))
+            .map(|(coord, _)| coord)
+            .collect()
     }
+}
 
+impl Graph<(usize, usize)> for Garden {
     fn start(&self) -> Option<(usize, usize)> {
         self.find(|(_, tile)| tile == &'S')
             .map(|(coord, _)| coord.to_owned())
     }
 
-    fn adjacent(&self, node: &(usize, usize)) -> Vec<(usize, usize)> {
+    fn adjacent(&self, node: &(usize, usize)) -> Option<Vec<(usize, usize)>> {
         let (x, y) = node;
 
-        [(0, 1), (0, -1), (1, 0), (-1, 0)]
-            .into_iter()
-            .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
-            .filter(|(x, y)| *x < self.width() && *y < self.height())
-            .filter(|coord| self.get(coord) != Some(&'#'))
-            .collect()
+        Some(
+            [(0, 1), (0, -1), (1, 0), (-1, 0)]
+                .into_iter()
+                .filter_map(|(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+                .filter(|(x, y)| *x < self.width() && *y < self.height())
+                .filter(|coord| self.get(coord) != Some(&'#'))
+                .collect(),
+        )
+    }
+
+    fn is_target(&self, _node: &(usize, usize)) -> bool {
+        false
+    }
+
+    fn estimated_size(&self) -> usize {
+        self.width() * self.height()
     }
 }
 
@@ -91,6 +89,7 @@ impl Garden {
 
 impl<const STEPS: usize> Solution for Day21<STEPS> {
     const TITLE: &'static str = "Step Counter";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 21;
     type Input = Garden;
     type P1 = usize;
@@ -100,30 +99,32 @@ impl<const STEPS: usize> Solution for Day21<STEPS> {
         Garden::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        let accepted = input.solve(input.start()?, STEPS);
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        let accepted = input.solve(input.start().ok_or(SolutionError::NoAnswer)?, STEPS);
 
         if is_flag("--print") {
             viz::print(&input.0, &accepted);
         }
 
-        Some(accepted.len())
+        Ok(accepted.len())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         const STEPS: usize = 26501365;
 
-        let grid_size = (input.width() == input.height()).then_some(input.height())?;
+        let grid_size = (input.width() == input.height())
+            .then_some(input.height())
+            .ok_or(SolutionError::NoAnswer)?;
         let checkerboard_width = STEPS / grid_size;
 
         if STEPS % grid_size != grid_size / 2 {
-            return None;
+            return Err(SolutionError::NoAnswer);
         }
 
         let n_odd_boxes = (checkerboard_width - 1).pow(2);
         let n_even_boxes = checkerboard_width.pow(2);
 
-        let start = input.start()?;
+        let start = input.start().ok_or(SolutionError::NoAnswer)?;
         let (start_x, start_y) = start;
         let cardinal_edges = [
             (0, start_y),
@@ -170,6 +171,7 @@ impl<const STEPS: usize> Solution for Day21<STEPS> {
                 * n_boxes
         })
         .sum1()
+        .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -3,60 +3,26 @@ use std::str::FromStr;
 
 use aoc::solution::SolutionError;
 use aoc::Solution;
+use aoc_utils::cuboid::Cuboid;
 use itertools::Itertools;
 
 #[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
-struct Point(usize, usize, usize);
-
-#[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Clone)]
-struct Brick(Point, Point);
-
-impl Point {
-    fn x(&self) -> usize {
-        self.0
-    }
-    fn y(&self) -> usize {
-        self.1
-    }
-    fn z(&self) -> usize {
-        self.2
-    }
-}
+struct Brick(Cuboid<usize, 3>);
 
 impl Brick {
-    fn move_z(&mut self, count: isize) {
-        self.0 .2 = self.0.z().saturating_add_signed(count);
-        self.1 .2 = self.1.z().saturating_add_signed(count);
-    }
-
-    fn intersect_xy(&self, other: &Self) -> bool {
-        let Brick(l1, r1) = other;
-        let Brick(l2, r2) = self;
-
-        let overlaps_x = !(r1.x() < l2.x() || l1.x() > r2.x());
-        let overlaps_y = !(r1.y() < l2.y() || l1.y() > r2.y());
-
-        overlaps_x && overlaps_y
+    fn bottom(&self) -> usize {
+        self.0.start[2]
     }
 
-    fn dist_z(&self, other: &Self) -> usize {
-        self.1
-            .z()
-            .abs_diff(other.0.z())
-            .min(self.0.z().abs_diff(other.1.z()))
+    fn fall(&mut self) {
+        self.0.start[2] -= 1;
+        self.0.end[2] -= 1;
     }
-}
-
-impl FromStr for Point {
-    type Err = SolutionError;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.trim()
-            .split(',')
-            .filter_map(|coord| coord.parse().ok())
-            .collect_tuple()
-            .map(|(x, y, z)| Point(x, y, z))
-            .ok_or(SolutionError::ParseError)
+    /// Whether `other` rests right on top of this brick.
+    fn supports(&self, other: &Self) -> bool {
+        other.0.start[2] == self.0.end[2]
+            && self.0.project([0, 1]).intersects(&other.0.project([0, 1]))
     }
 }
 
@@ -64,9 +30,17 @@ impl FromStr for Brick {
     type Err = SolutionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let corner = |s: &str| {
+            s.trim()
+                .split(',')
+                .filter_map(|coord| coord.parse().ok())
+                .collect_tuple()
+                .map(|(x, y, z)| [x, y, z])
+                .ok_or(SolutionError::ParseError)
+        };
         let (start, end) = s.split_once('~').ok_or(SolutionError::ParseError)?;
 
-        Ok(Brick(start.parse()?, end.parse()?))
+        Ok(Brick(Cuboid::inclusive(corner(start)?, corner(end)?)))
     }
 }
 
@@ -75,20 +49,12 @@ struct Day22;
 type Child<'a> = (&'a Brick, Vec<&'a Brick>);
 
 impl Day22 {
-    fn overlaps(brick: &Brick, other: &Brick) -> bool {
-        brick.intersect_xy(other)
-    }
-
     fn tetris(input: &[Brick]) -> Vec<Brick> {
-        let mut stack = vec![];
+        let mut stack: Vec<Brick> = vec![];
 
-        for mut brick in input.iter().sorted_by_key(|b| b.0.z()).cloned() {
-            while brick.0.z() > 0
-                && !stack
-                    .iter()
-                    .any(|other| brick.intersect_xy(other) && brick.dist_z(other) == 1)
-            {
-                brick.move_z(-1);
+        for mut brick in input.iter().sorted_by_key(|b| b.bottom()).cloned() {
+            while brick.bottom() > 0 && !stack.iter().any(|other| other.supports(&brick)) {
+                brick.fall();
             }
 
             stack.push(brick);
@@ -105,18 +71,13 @@ impl Day22 {
                     brick,
                     stack
                         .iter()
-                        .filter(|other| {
-                            other.0.z() == brick.1.z() + 1 && Day22::overlaps(brick, other)
-                        })
+                        .filter(|other| brick.supports(other))
                         .map(|dep| {
                             (
                                 dep,
                                 stack
                                     .iter()
-                                    .filter(|other| {
-                                        (other.1.z() == dep.0.z() - 1)
-                                            && Day22::overlaps(dep, other)
-                                    })
+                                    .filter(|other| other.supports(dep))
                                     .collect_vec(),
                             )
                         })
@@ -154,6 +115,7 @@ impl Day22 {
 
 impl Solution for Day22 {
     const TITLE: &'static str = "Sand Slabs";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 22;
     type Input = Vec<Brick>;
     type P1 = usize;
@@ -168,32 +130,31 @@ impl Solution for Day22 {
                 Day22::tetris(
                     &bricks
                         .into_iter()
-                        .sorted_by_key(|brick| brick.0.z())
+                        .sorted_by_key(|brick| brick.bottom())
                         .collect_vec(),
                 )
             })
     }
 
-    fn part1(stack: &Self::Input) -> Option<Self::P1> {
-        Some(
-            Day22::dependencies(stack)
-                .iter()
-                .filter(|(base, children)| {
-                    children.iter().all(|(_, parents)| {
-                        parents.iter().filter(|parent| parent != base).count() > 0
-                    })
-                })
-                .count(),
-        )
+    fn part1(stack: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(Day22::dependencies(stack)
+            .iter()
+            .filter(|(base, children)| {
+                children
+                    .iter()
+                    .all(|(_, parents)| parents.iter().filter(|parent| parent != base).count() > 0)
+            })
+            .count())
     }
 
-    fn part2(stack: &Self::Input) -> Option<Self::P2> {
+    fn part2(stack: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let dependencies = Day22::dependencies(stack);
 
         stack
             .iter()
             .map(|brick| Day22::chain_reaction(brick, &dependencies).len())
             .sum1()
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
@@ -1,17 +1,22 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use aoc::solution::SolutionError;
 use aoc::Solution;
 
 use aoc_utils::is_flag;
-use aoc_utils::pathfinding::Graph;
+use aoc_utils::pathfinding::{longest, Compressed, Graph};
 
 type Coord = (usize, usize);
 
 struct Trail(Vec<Vec<char>>);
 
-struct Hike(Coord, Coord, HashMap<Coord, HashMap<Coord, usize>>);
+struct Walk<'a> {
+    trail: &'a Trail,
+    slopes: HashMap<char, Vec<(isize, isize)>>,
+}
+
+struct Hike(Compressed<Coord, usize>);
 
 impl Trail {
     fn get(&self, coord: &Coord) -> Option<&char> {
@@ -42,69 +47,28 @@ impl Trail {
     }
 }
 
-impl Hike {
-    fn new(trail: &Trail, dirs: HashMap<char, Vec<(isize, isize)>>) -> Self {
-        const ALL_DIRS: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, -1), (0, 1)];
-        let start = (1, 0);
-        let target = (trail.width() - 2, trail.height() - 1);
-
-        let nodes = {
-            let mut intersections = vec![start, target];
-
-            for (y, row) in trail.0.iter().enumerate() {
-                for (x, tile) in row.iter().enumerate() {
-                    if tile != &'#' && trail.adjacent(&(x, y), &ALL_DIRS).len() > 2 {
-                        intersections.push((x, y));
-                    }
-                }
-            }
-
-            intersections
-        };
-
-        let mut graph: HashMap<_, HashMap<_, _>> = HashMap::new();
-
-        for node in nodes.iter() {
-            let node = *node;
-            let mut queue = VecDeque::new();
-            let mut visited = HashSet::new();
-
-            queue.push_back((node, 0));
-            visited.insert(node);
-
-            while let Some((current, dist)) = queue.pop_front() {
-                if dist > 0 && nodes.contains(&current) {
-                    *graph.entry(node).or_default().entry(current).or_default() = dist;
-                    continue;
-                }
-
-                let tile = trail.get(&current).unwrap_or(&'.');
+const ALL_DIRS: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, -1), (0, 1)];
 
-                for next in trail.adjacent(&current, dirs.get(tile).unwrap_or(&ALL_DIRS.to_vec())) {
-                    if visited.insert(next) {
-                        queue.push_back((next, dist + 1))
-                    }
-                }
-            }
-        }
-
-        Self(start, target, graph)
-    }
-}
-
-impl Graph<Coord> for Hike {
+impl Graph<Coord> for Walk<'_> {
     fn start(&self) -> Option<Coord> {
-        Some(self.0)
+        Some((1, 0))
     }
 
     fn adjacent(&self, current: &Coord) -> Option<Vec<Coord>> {
-        self.2
-            .get(current)
-            .map(|children| children.keys().cloned().collect())
+        let tile = self.trail.get(current)?;
+        let deltas = self.slopes.get(tile).map_or(&ALL_DIRS[..], |dirs| dirs);
+
+        Some(self.trail.adjacent(current, deltas))
     }
 
     fn is_target(&self, node: &Coord) -> bool {
-        &self.1 == node
+        node == &(self.trail.width() - 2, self.trail.height() - 1)
+    }
+}
+
+impl Hike {
+    fn new(trail: &Trail, slopes: HashMap<char, Vec<(isize, isize)>>) -> Self {
+        Self(longest::contract(&Walk { trail, slopes }, |_, _| Some(1)))
     }
 }
 
@@ -120,50 +84,22 @@ impl FromStr for Trail {
 
 impl Hike {
     fn longest_path(&self) -> Option<usize> {
-        let mut output = Default::default();
-
-        self.solve_dfs(self.0, 0, &mut Default::default(), &mut output);
-
-        output.into_iter().max()
+        longest::simple(&self.0, |current, next| self.0.cost(*current, *next))
     }
 
-    fn solve_dfs(
-        &self,
-        current: Coord,
-        cost: usize,
-        history: &mut HashSet<Coord>,
-        distances: &mut Vec<usize>,
-    ) {
-        let target = self.1;
-        let graph = &self.2;
-
-        if current == target {
-            distances.push(cost);
-            return;
-        }
-
-        if history.insert(current) {
-            if let Some(nodes) = graph.get(&current) {
-                for (next, next_cost) in nodes {
-                    if !history.contains(next) {
-                        self.solve_dfs(*next, cost + next_cost, history, distances);
-                    }
-                }
-            }
-
-            history.remove(&current);
-        }
+    fn longest_descent(&self) -> Option<usize> {
+        longest::dag(&self.0, |current, next| self.0.cost(*current, *next))
     }
 }
 
 mod vis {
-    use crate::{Hike, Trail};
+    use super::{Hike, Trail};
 
     impl Hike {
         pub fn dbg(&self, trail: &Trail) {
             for (y, row) in trail.0.iter().enumerate() {
                 for (x, tile) in row.iter().enumerate() {
-                    if self.2.contains_key(&(x, y)) {
+                    if self.0.index_of(&(x, y)).is_some() {
                         print!(" X ");
                     } else {
                         match tile {
@@ -183,6 +119,7 @@ struct Day23;
 
 impl Solution for Day23 {
     const TITLE: &'static str = "A Long Walk";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 23;
     type Input = Trail;
     type P1 = usize;
@@ -192,7 +129,7 @@ impl Solution for Day23 {
         Trail::from_str(input)
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
         let slopes: HashMap<_, _> = [
             ('>', vec![(1, 0)]),
             ('<', vec![(-1, 0)]),
@@ -208,13 +145,13 @@ impl Solution for Day23 {
             hike.dbg(input);
         });
 
-        hike.longest_path()
+        hike.longest_descent().ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
         let hike = Hike::new(input, Default::default());
 
-        hike.longest_path()
+        hike.longest_path().ok_or(SolutionError::NoAnswer)
     }
 }
 
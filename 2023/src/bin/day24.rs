@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
-use aoc::solution::SolutionError;
+use aoc::solution::{parse_lines, SolutionError};
 use aoc::Solution;
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
@@ -142,43 +142,50 @@ impl<const MIN: usize, const MAX: usize> Day24<MIN, MAX> {
 
 impl<const MIN: usize, const MAX: usize> Solution for Day24<MIN, MAX> {
     const TITLE: &'static str = "Never Tell Me The Odds";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 24;
     type Input = Vec<Hail>;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-        input.lines().map(Hail::from_str).collect()
+        parse_lines(input).collect()
     }
 
-    fn part1(input: &Self::Input) -> Option<Self::P1> {
-        Some(
-            input
-                .iter()
-                .tuple_combinations()
-                .filter_map(|(p1, p2)| p1.intersects(p2))
-                .filter(|(x, _)| MIN as f64 <= *x && *x <= MAX as f64)
-                .filter(|(_, y)| MIN as f64 <= *y && *y <= MAX as f64)
-                .count(),
-        )
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        Ok(input
+            .iter()
+            .tuple_combinations()
+            .filter_map(|(p1, p2)| p1.intersects(p2))
+            .filter(|(x, _)| MIN as f64 <= *x && *x <= MAX as f64)
+            .filter(|(_, y)| MIN as f64 <= *y && *y <= MAX as f64)
+            .count())
     }
 
-    fn part2(input: &Self::Input) -> Option<Self::P2> {
-        let vx = Self::brute_force_velocity(input, |h| h.x(), |h| h.vx())? as f64;
-        let vy = Self::brute_force_velocity(input, |h| h.y(), |h| h.vy())? as f64;
-        let vz = Self::brute_force_velocity(input, |h| h.z(), |h| h.vz())? as f64;
+    fn part2(input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        let vx = Self::brute_force_velocity(input, |h| h.x(), |h| h.vx())
+            .ok_or(SolutionError::NoAnswer)? as f64;
+        let vy = Self::brute_force_velocity(input, |h| h.y(), |h| h.vy())
+            .ok_or(SolutionError::NoAnswer)? as f64;
+        let vz = Self::brute_force_velocity(input, |h| h.z(), |h| h.vz())
+            .ok_or(SolutionError::NoAnswer)? as f64;
 
         let (a, b) = input
             .iter()
             .take(2)
             .map(|h| Hail::new((h.point, (h.vx() - vx, h.vy() - vy, h.vz() - vz))))
-            .collect_tuple()?;
+            .collect_tuple()
+            .ok_or(SolutionError::NoAnswer)?;
 
-        let (x, y) = a.intersects(&b)?;
+        let (x, y) = a.intersects(&b).ok_or(SolutionError::NoAnswer)?;
         let t = (x - a.x()) / a.vx();
         let z = a.z() + a.vz() * t;
 
-        [x, y, z].iter().sum1().map(|sum: f64| sum as usize)
+        [x, y, z]
+            .iter()
+            .sum1()
+            .map(|sum: f64| sum as usize)
+            .ok_or(SolutionError::NoAnswer)
     }
 }
 
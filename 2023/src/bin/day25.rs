@@ -1,59 +1,43 @@
-use std::collections::{HashMap, HashSet};
-
 use aoc::solution::SolutionError;
 use aoc::Solution;
+use aoc_utils::graph::UndirectedGraph;
 
 pub struct Day25;
 
 impl Solution for Day25 {
     const TITLE: &'static str = "Snowverload";
+    const YEAR: u16 = 2023;
     const DAY: u8 = 25;
-    type Input = HashMap<String, HashSet<String>>;
+    type Input = UndirectedGraph;
     type P1 = usize;
     type P2 = usize;
 
     fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
         input
             .lines()
-            .try_fold(HashMap::<_, HashSet<_>>::new(), |mut graph, line| {
-                let (node, children) = line.split_once(": ")?;
+            .try_fold(UndirectedGraph::new(), |mut graph, line| {
+                let (node, children) = line
+                    .split_once(": ")
+                    .ok_or_else(|| SolutionError::expected(input, line, "'node: children...'"))?;
 
                 for child in children.split_whitespace() {
-                    let node = node.trim().to_owned();
-                    let child = child.trim().to_owned();
-
-                    graph.entry(node.clone()).or_default().insert(child.clone());
-                    graph.entry(child).or_default().insert(node);
+                    graph.add_edge(node.trim(), child.trim());
                 }
 
-                Some(graph)
+                Ok(graph)
             })
-            .ok_or(SolutionError::ParseError)
     }
 
-    fn part1(original: &Self::Input) -> Option<Self::P1> {
-        let nodes: HashSet<_> = original.keys().cloned().collect();
-        let mut derived = nodes.clone();
-
-        while (derived
-            .iter()
-            .map(|k| original.get(k).unwrap().difference(&derived).count())
-            .sum::<usize>())
-            != 3
-        {
-            let best_candidate = derived
-                .iter()
-                .max_by_key(|&k| original.get(k).unwrap().difference(&derived).count())
-                .cloned()?;
-
-            derived.remove(&best_candidate);
-        }
+    fn part1(input: &Self::Input) -> aoc::solution::Result<Self::P1> {
+        let cut = input.min_cut().ok_or(SolutionError::NoAnswer)?;
 
-        Some(derived.len() * nodes.difference(&derived).count())
+        (cut.weight == 3)
+            .then(|| cut.side.len() * (input.len() - cut.side.len()))
+            .ok_or(SolutionError::NoAnswer)
     }
 
-    fn part2(_input: &Self::Input) -> Option<Self::P2> {
-        None
+    fn part2(_input: &Self::Input) -> aoc::solution::Result<Self::P2> {
+        Err(SolutionError::NoAnswer)
     }
 }
 
@@ -0,0 +1,3 @@
+//! Every day of the year, registered for the `aoc` binary, see `build.rs`.
+
+include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
@@ -1,40 +1,131 @@
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut, RangeInclusive};
 
-#[derive(Debug, Clone)]
-pub struct Matrix<T>(Vec<((usize, usize), T)>);
+/// Dense 2D grid stored row-major.
+///
+/// Cells are addressed with `(x, y)` coordinates where `x` is the column and `y` the row.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Matrix<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// A row of a [`Matrix`] whose width differs from the first row's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaggedRow {
+    /// Index of the row.
+    pub row: usize,
+    /// Width of the first row.
+    pub width: usize,
+}
+
+impl Display for RaggedRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} has a different width than the first one ({})",
+            self.row, self.width
+        )
+    }
+}
+
+impl std::error::Error for RaggedRow {}
 
 impl<T> Matrix<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Matrix just large enough to hold every cell of `cells`, the missing ones set to `fill`.
+    pub fn from_sparse(cells: HashMap<(usize, usize), T>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = cells.keys().map(|(x, _)| x + 1).max().unwrap_or_default();
+        let height = cells.keys().map(|(_, y)| y + 1).max().unwrap_or_default();
+        let mut matrix = Self::new(width, height, fill);
+
+        for (coord, value) in cells {
+            matrix[coord] = value;
+        }
+
+        matrix
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        Self {
+            width,
+            height,
+            cells: (0..width * height)
+                .map(|i| f((i % width, i / width)))
+                .collect(),
+        }
+    }
+
     pub fn inner(self) -> Vec<((usize, usize), T)> {
-        self.0
+        let width = self.width;
+
+        self.cells
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| ((i % width, i / width), c))
+            .collect()
     }
 
     pub fn width(&self) -> usize {
-        self.0
-            .iter()
-            .map(|((x, _), _)| x + 1)
-            .max()
-            .unwrap_or_default()
+        self.width
     }
+
     pub fn height(&self) -> usize {
-        self.0
-            .iter()
-            .map(|((_, y), _)| y + 1)
-            .max()
-            .unwrap_or_default()
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// row-major index of `coord`, `None` when out of bounds
+    pub fn index_of(&self, &(x, y): &(usize, usize)) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
     }
 
     pub fn get(&self, coord: &(usize, usize)) -> Option<&T> {
-        self.0
-            .iter()
-            .find_map(|(k, v)| if coord == k { Some(v) } else { None })
+        self.index_of(coord).map(|i| &self.cells[i])
     }
 
     pub fn get_mut(&mut self, coord: &(usize, usize)) -> Option<&mut T> {
-        self.0
-            .iter_mut()
-            .find_map(|(k, v)| if coord == k { Some(v) } else { None })
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        (y < self.height).then(|| &mut self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn col(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// contiguous slices for each row, top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
     }
 
     ///iter on each row
@@ -47,16 +138,10 @@ impl<T> Matrix<T> {
     /// yields [a, a, ...] [b, b, ...] [c, c, ...]
     /// ```
     ///
-    pub fn iter_row(&self) -> impl Iterator<Item = (usize, impl Iterator<Item = (&usize, &T)>)> {
-        (0..self.height()).map(|k| {
-            (
-                k,
-                self.0
-                    .iter()
-                    .filter(move |((_, y), _)| *y == k)
-                    .map(|((x, _), c)| (x, c)),
-            )
-        })
+    pub fn iter_row(&self) -> impl Iterator<Item = (usize, impl Iterator<Item = (usize, &T)>)> {
+        self.rows()
+            .enumerate()
+            .map(|(y, row)| (y, row.iter().enumerate()))
     }
 
     ///iter on each column
@@ -71,51 +156,122 @@ impl<T> Matrix<T> {
     /// yields [a, b, c], [a, b, c] ....
     /// ```
     ///
-    pub fn iter_col(&self) -> impl Iterator<Item = (usize, impl Iterator<Item = (&usize, &T)>)> {
-        (0..self.width()).map(|k| {
-            (
-                k,
-                self.0
-                    .iter()
-                    .filter(move |((x, _), _)| *x == k)
-                    .map(|((_, y), c)| (y, c)),
-            )
-        })
+    pub fn iter_col(&self) -> impl Iterator<Item = (usize, impl Iterator<Item = (usize, &T)>)> {
+        (0..self.width).map(|x| (x, self.cells.iter().skip(x).step_by(self.width).enumerate()))
     }
 
     /// iter on the matrix
     /// ```text
     /// yields [row * col]
     /// ```
-    pub fn iter(&self) -> impl Iterator<Item = &((usize, usize), T)> {
-        self.0.iter()
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, c)| ((i % width, i / width), c))
     }
 
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut ((usize, usize), T)> {
-        self.0.iter_mut()
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let width = self.width;
+
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, c)| ((i % width, i / width), c))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, coord: (usize, usize)) -> &Self::Output {
+        self.get(&coord).expect("coordinate out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, coord: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(&coord).expect("coordinate out of bounds")
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Builds the matrix from an iterator of rows, failing on the first row whose width differs.
+    pub fn try_from_rows<I: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = I>,
+    ) -> Result<Self, RaggedRow> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for row in rows {
+            let before = cells.len();
+
+            cells.extend(row);
+            height += 1;
+
+            let len = cells.len() - before;
+            let width = *width.get_or_insert(len);
+
+            if len != width {
+                return Err(RaggedRow {
+                    row: height - 1,
+                    width,
+                });
+            }
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
     }
 }
 
 impl<I: Iterator> FromIterator<I> for Matrix<I::Item> {
+    /// Builds the matrix from an iterator of rows.
+    ///
+    /// # Panics
+    /// if the rows don't all have the same length, see [`Matrix::try_from_rows`]
     fn from_iter<T: IntoIterator<Item = I>>(iter: T) -> Self {
-        Self(
-            iter.into_iter()
-                .enumerate()
-                .flat_map(|(y, row)| row.enumerate().map(move |(x, c)| ((x, y), c)))
-                .collect(),
-        )
+        Self::try_from_rows(iter).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl Matrix<char> {
+    /// One cell per character, one row per line, failing on the first line whose width differs.
+    pub fn try_from_str(value: &str) -> Result<Self, RaggedRow> {
+        Self::try_from_rows(value.lines().map(|row| row.chars()))
     }
 }
 
 impl From<&str> for Matrix<char> {
+    /// One cell per character, one row per line.
+    ///
+    /// # Panics
+    /// if the lines don't all have the same length, see [`Matrix::try_from_str`]
     fn from(value: &str) -> Self {
-        value.lines().map(|row| row.chars()).collect()
+        Self::try_from_str(value).unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
 impl<T> From<HashMap<(usize, usize), T>> for Matrix<T> {
-    fn from(value: HashMap<(usize, usize), T>) -> Self {
-        Self(value.into_iter().collect())
+    /// Matrix just large enough to hold every cell of `value`.
+    ///
+    /// # Panics
+    /// if a coordinate within those bounds is missing, see [`Matrix::from_sparse`]
+    fn from(mut value: HashMap<(usize, usize), T>) -> Self {
+        let width = value.keys().map(|(x, _)| x + 1).max().unwrap_or_default();
+        let height = value.keys().map(|(_, y)| y + 1).max().unwrap_or_default();
+
+        Self::from_fn(width, height, |coord| {
+            value
+                .remove(&coord)
+                .unwrap_or_else(|| panic!("missing cell at {coord:?}"))
+        })
     }
 }
 
@@ -133,3 +289,403 @@ impl Display for Matrix<char> {
         )
     }
 }
+
+/// Signed `(x, y)` coordinate used by [`SparseGrid`].
+pub type Coord = (i64, i64);
+
+/// Offsets of the 4 orthogonal neighbours.
+pub const ORTHOGONAL: [Coord; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 surrounding neighbours, clockwise from north.
+pub const ADJACENT: [Coord; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Inclusive bounding box of a [`SparseGrid`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Bounds {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl Bounds {
+    pub fn width(&self) -> u64 {
+        self.max.0.abs_diff(self.min.0) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.max.1.abs_diff(self.min.1) + 1
+    }
+
+    pub fn area(&self) -> u64 {
+        self.width() * self.height()
+    }
+
+    pub fn xs(&self) -> RangeInclusive<i64> {
+        self.min.0..=self.max.0
+    }
+
+    pub fn ys(&self) -> RangeInclusive<i64> {
+        self.min.1..=self.max.1
+    }
+
+    pub fn contains(&self, &(x, y): &Coord) -> bool {
+        self.xs().contains(&x) && self.ys().contains(&y)
+    }
+
+    /// grows the box by `margin` on every side
+    pub fn pad(&self, margin: i64) -> Self {
+        Self {
+            min: (self.min.0 - margin, self.min.1 - margin),
+            max: (self.max.0 + margin, self.max.1 + margin),
+        }
+    }
+}
+
+/// Unbounded 2D grid keyed by signed coordinates.
+///
+/// Only occupied cells are stored. The bounding box is kept up to date on every insertion and
+/// removal by counting occupied cells per column and per row.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    cols: BTreeMap<i64, usize>,
+    rows: BTreeMap<i64, usize>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            cols: BTreeMap::new(),
+            rows: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        let (&min_x, _) = self.cols.first_key_value()?;
+        let (&max_x, _) = self.cols.last_key_value()?;
+        let (&min_y, _) = self.rows.first_key_value()?;
+        let (&max_y, _) = self.rows.last_key_value()?;
+
+        Some(Bounds {
+            min: (min_x, min_y),
+            max: (max_x, max_y),
+        })
+    }
+
+    pub fn contains(&self, coord: &Coord) -> bool {
+        self.cells.contains_key(coord)
+    }
+
+    pub fn get(&self, coord: &Coord) -> Option<&T> {
+        self.cells.get(coord)
+    }
+
+    pub fn get_mut(&mut self, coord: &Coord) -> Option<&mut T> {
+        self.cells.get_mut(coord)
+    }
+
+    pub fn insert(&mut self, coord: Coord, value: T) -> Option<T> {
+        let previous = self.cells.insert(coord, value);
+
+        if previous.is_none() {
+            *self.cols.entry(coord.0).or_default() += 1;
+            *self.rows.entry(coord.1).or_default() += 1;
+        }
+
+        previous
+    }
+
+    pub fn remove(&mut self, coord: &Coord) -> Option<T> {
+        let value = self.cells.remove(coord)?;
+
+        Self::release(&mut self.cols, coord.0);
+        Self::release(&mut self.rows, coord.1);
+
+        Some(value)
+    }
+
+    fn release(counts: &mut BTreeMap<i64, usize>, key: i64) {
+        if let Some(count) = counts.get_mut(&key) {
+            *count -= 1;
+
+            if *count == 0 {
+                counts.remove(&key);
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Coord, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Coord> {
+        self.cells.keys()
+    }
+
+    /// occupied cells around `coord`, at the given `offsets` (see [`ORTHOGONAL`] and [`ADJACENT`])
+    pub fn neighbors<'a>(
+        &'a self,
+        &(x, y): &Coord,
+        offsets: &'a [Coord],
+    ) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let next = (x + dx, y + dy);
+
+            self.get(&next).map(|value| (next, value))
+        })
+    }
+
+    pub fn count_neighbors(&self, coord: &Coord, offsets: &[Coord]) -> usize {
+        self.neighbors(coord, offsets).count()
+    }
+
+    /// moves every cell by `(dx, dy)`
+    pub fn translate(&mut self, (dx, dy): Coord) {
+        let shift = |counts: &mut BTreeMap<i64, usize>, delta: i64| {
+            *counts = std::mem::take(counts)
+                .into_iter()
+                .map(|(k, count)| (k + delta, count))
+                .collect();
+        };
+
+        self.cells = std::mem::take(&mut self.cells)
+            .into_iter()
+            .map(|((x, y), value)| ((x + dx, y + dy), value))
+            .collect();
+        shift(&mut self.cols, dx);
+        shift(&mut self.rows, dy);
+    }
+
+    /// draws the grid within its bounds, top row first
+    pub fn render(&self, glyph: impl Fn(Coord, Option<&T>) -> char) -> String {
+        self.bounds()
+            .map(|bounds| self.render_within(&bounds, glyph))
+            .unwrap_or_default()
+    }
+
+    /// draws the cells within `bounds`, top row first
+    pub fn render_within(
+        &self,
+        bounds: &Bounds,
+        glyph: impl Fn(Coord, Option<&T>) -> char,
+    ) -> String {
+        bounds
+            .ys()
+            .map(|y| {
+                bounds
+                    .xs()
+                    .map(|x| glyph((x, y), self.get(&(x, y))))
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T> Extend<(Coord, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Coord, T)>>(&mut self, iter: I) {
+        for (coord, value) in iter {
+            self.insert(coord, value);
+        }
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl FromIterator<Coord> for SparseGrid<()> {
+    fn from_iter<I: IntoIterator<Item = Coord>>(iter: I) -> Self {
+        iter.into_iter().map(|coord| (coord, ())).collect()
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = (Coord, T);
+    type IntoIter = std::collections::hash_map::IntoIter<Coord, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+impl Display for SparseGrid<char> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|_, c| c.copied().unwrap_or('.')))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collections::*;
+
+    const GRID: &str = "abc\r\ndef\r\n";
+
+    #[test]
+    fn matrix_from_str() {
+        let matrix = Matrix::from(GRID);
+
+        assert_eq!((3, 2), (matrix.width(), matrix.height()));
+        assert_eq!(Some(&'a'), matrix.get(&(0, 0)));
+        assert_eq!(Some(&'f'), matrix.get(&(2, 1)));
+        assert_eq!(None, matrix.get(&(3, 0)));
+        assert_eq!('e', matrix[(1, 1)]);
+    }
+
+    #[test]
+    fn matrix_rows_and_cols() {
+        let matrix = Matrix::from(GRID);
+
+        assert_eq!(Some(&['d', 'e', 'f'][..]), matrix.row(1));
+        assert_eq!(vec![&'b', &'e'], matrix.col(1).unwrap().collect_vec());
+        assert_eq!(
+            vec![(2, vec!['c', 'f'])],
+            matrix
+                .iter_col()
+                .skip(2)
+                .map(|(x, col)| (x, col.map(|(_, c)| *c).collect_vec()))
+                .collect_vec()
+        );
+    }
+
+    #[test]
+    fn matrix_from_hashmap() {
+        let cells = HashMap::from([((0, 0), 1), ((1, 0), 2), ((0, 1), 3), ((1, 1), 4)]);
+        let matrix = Matrix::from(cells);
+
+        assert_eq!((2, 2), (matrix.width(), matrix.height()));
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            matrix.iter().map(|(_, c)| *c).collect_vec()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "missing cell at (1, 0)")]
+    fn matrix_from_hashmap_gaps() {
+        let _ = Matrix::from(HashMap::from([((0, 0), 1), ((2, 1), 2)]));
+    }
+
+    #[test]
+    fn matrix_from_sparse() {
+        let matrix = Matrix::from_sparse(HashMap::from([((0, 0), 1), ((2, 1), 2)]), 0);
+
+        assert_eq!((3, 2), (matrix.width(), matrix.height()));
+        assert_eq!(
+            vec![1, 0, 0, 0, 0, 2],
+            matrix.iter().map(|(_, c)| *c).collect_vec()
+        );
+    }
+
+    #[test]
+    fn matrix_ragged_rows() {
+        assert_eq!(
+            Err(RaggedRow { row: 2, width: 2 }),
+            Matrix::try_from_str("ab\ncd\ne\nfg")
+        );
+        assert_eq!(Ok(Matrix::new(0, 0, '.')), Matrix::try_from_str(""));
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has a different width")]
+    fn matrix_ragged_rows_collect() {
+        let _: Matrix<u8> = [vec![1, 2], vec![3]]
+            .into_iter()
+            .map(Vec::into_iter)
+            .collect();
+    }
+
+    #[test]
+    fn matrix_display() {
+        assert_eq!(
+            "   0 1 2\n 0 a b c\n 1 d e f",
+            Matrix::from(GRID).to_string()
+        );
+    }
+
+    #[test]
+    fn sparse_grid_bounds() {
+        let mut grid: SparseGrid<()> = [(-2, 1), (3, -4), (0, 0)].into_iter().collect();
+
+        assert_eq!(
+            Some(Bounds {
+                min: (-2, -4),
+                max: (3, 1)
+            }),
+            grid.bounds()
+        );
+
+        grid.remove(&(3, -4));
+        assert_eq!(
+            Some(Bounds {
+                min: (-2, 0),
+                max: (0, 1)
+            }),
+            grid.bounds()
+        );
+
+        grid.remove(&(-2, 1));
+        grid.remove(&(0, 0));
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn sparse_grid_neighbors() {
+        let grid: SparseGrid<()> = [(0, 0), (1, 0), (1, 1), (-1, -1), (0, 2)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(1, grid.count_neighbors(&(0, 0), &ORTHOGONAL));
+        assert_eq!(3, grid.count_neighbors(&(0, 0), &ADJACENT));
+    }
+
+    #[test]
+    fn sparse_grid_translate_and_render() {
+        let mut grid: SparseGrid<char> = [((0, 0), 'a'), ((2, 1), 'b')].into_iter().collect();
+
+        assert_eq!("a..\n..b", grid.to_string());
+
+        grid.translate((-5, 10));
+        assert_eq!(Some(&'b'), grid.get(&(-3, 11)));
+        assert_eq!(Some((-5, 10)), grid.bounds().map(|b| b.min));
+        assert_eq!(
+            "     \n a   \n   b \n     ",
+            grid.render_within(&grid.bounds().unwrap().pad(1), |_, c| *c.unwrap_or(&' '))
+        );
+    }
+}
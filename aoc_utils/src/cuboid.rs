@@ -0,0 +1,275 @@
+//! Axis-aligned boxes of integer points in any number of dimensions.
+
+use std::ops::Range;
+
+use crate::number::Integer;
+
+/// The points `p` with `start[i] <= p[i] < end[i]` on every axis `i`.
+///
+/// ```
+/// use aoc_utils::cuboid::{union_volume, Cuboid};
+///
+/// let a = Cuboid::new([0, 0, 0], [3, 3, 3]);
+/// let b = Cuboid::inclusive([2, 2, 2], [4, 4, 4]);
+///
+/// assert_eq!(Some(Cuboid::new([2, 2, 2], [3, 3, 3])), a.intersection(&b));
+/// assert_eq!(26, a.subtract(&b).iter().map(Cuboid::volume).sum::<i32>());
+/// assert_eq!(27 + 27 - 1, union_volume([a, b]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub start: [T; N],
+    pub end: [T; N],
+}
+
+impl<T: Integer, const N: usize> Cuboid<T, N> {
+    pub fn new(start: [T; N], end: [T; N]) -> Self {
+        Self { start, end }
+    }
+
+    /// The box with both corners included, given in any order.
+    pub fn inclusive(a: [T; N], b: [T; N]) -> Self {
+        Self {
+            start: std::array::from_fn(|i| a[i].min(b[i])),
+            end: std::array::from_fn(|i| a[i].max(b[i]) + T::ONE),
+        }
+    }
+
+    /// Smallest box containing all the points, `None` without points.
+    pub fn bounding(points: impl IntoIterator<Item = [T; N]>) -> Option<Self> {
+        points
+            .into_iter()
+            .map(|point| Self::inclusive(point, point))
+            .reduce(|a, b| Self {
+                start: std::array::from_fn(|i| a.start[i].min(b.start[i])),
+                end: std::array::from_fn(|i| a.end[i].max(b.end[i])),
+            })
+    }
+
+    pub fn range(&self, axis: usize) -> Range<T> {
+        self.start[axis]..self.end[axis]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|i| self.start[i] >= self.end[i])
+    }
+
+    /// Number of points in the box.
+    pub fn volume(&self) -> T {
+        match self.is_empty() {
+            true => T::ZERO,
+            false => (0..N).fold(T::ONE, |volume, i| volume * (self.end[i] - self.start[i])),
+        }
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        (0..N).all(|i| self.start[i] <= point[i] && point[i] < self.end[i])
+    }
+
+    /// The box grown by `by` on every side.
+    pub fn expand(&self, by: T) -> Self {
+        Self {
+            start: self.start.map(|start| start - by),
+            end: self.end.map(|end| end + by),
+        }
+    }
+
+    /// The box on the given axes only, in their order.
+    pub fn project<const M: usize>(&self, axes: [usize; M]) -> Cuboid<T, M> {
+        Cuboid {
+            start: axes.map(|axis| self.start[axis]),
+            end: axes.map(|axis| self.end[axis]),
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self {
+            start: std::array::from_fn(|i| self.start[i].max(other.start[i])),
+            end: std::array::from_fn(|i| self.end[i].min(other.end[i])),
+        };
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Splits the box by the plane `p[axis] = at` into the points below it and the others, either
+    /// being `None` when empty.
+    pub fn split(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let (mut below, mut above) = (*self, *self);
+
+        below.end[axis] = at.max(self.start[axis]).min(self.end[axis]);
+        above.start[axis] = below.end[axis];
+
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// The points of the box outside of `other`, as at most `2 * N` disjoint boxes.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(hole) = self.intersection(other) else {
+            return match self.is_empty() {
+                true => vec![],
+                false => vec![*self],
+            };
+        };
+        let mut rest = *self;
+        let mut pieces = vec![];
+
+        // peel the slabs on both sides of the hole, one axis at a time
+        for axis in 0..N {
+            let (below, middle) = rest.split(axis, hole.start[axis]);
+            let (middle, above) = middle.map_or((None, None), |m| m.split(axis, hole.end[axis]));
+
+            pieces.extend(below.into_iter().chain(above));
+            rest = middle.unwrap_or(hole);
+        }
+
+        pieces
+    }
+}
+
+/// Number of points in at least one of the boxes.
+pub fn union_volume<T: Integer, const N: usize>(
+    cuboids: impl IntoIterator<Item = Cuboid<T, N>>,
+) -> T {
+    let mut disjoint: Vec<Cuboid<T, N>> = vec![];
+
+    for cuboid in cuboids {
+        let pieces = disjoint.iter().fold(vec![cuboid], |pieces, other| {
+            pieces
+                .iter()
+                .flat_map(|piece| piece.subtract(other))
+                .collect()
+        });
+
+        disjoint.extend(pieces);
+    }
+
+    disjoint
+        .iter()
+        .fold(T::ZERO, |volume, cuboid| volume + cuboid.volume())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cuboid::*;
+    use std::collections::HashSet;
+
+    /// Every box within `0..SIZE` on both axes, empty ones included, is checked against its points.
+    const SIZE: i32 = 4;
+
+    fn cuboids() -> Vec<Cuboid<i32, 2>> {
+        let ranges: Vec<_> = (0..=SIZE)
+            .flat_map(|start| (start..=SIZE).map(move |end| (start, end)))
+            .collect();
+
+        ranges
+            .iter()
+            .flat_map(|&(x0, x1)| {
+                ranges
+                    .iter()
+                    .map(move |&(y0, y1)| Cuboid::new([x0, y0], [x1, y1]))
+            })
+            .collect()
+    }
+
+    fn points(cuboid: &Cuboid<i32, 2>) -> HashSet<[i32; 2]> {
+        cuboid
+            .range(0)
+            .flat_map(|x| cuboid.range(1).map(move |y| [x, y]))
+            .collect()
+    }
+
+    #[test]
+    fn single_cuboids() {
+        for a in cuboids() {
+            let points = points(&a);
+
+            assert_eq!(points.len() as i32, a.volume());
+            assert_eq!(points.is_empty(), a.is_empty());
+
+            for x in -1..=SIZE {
+                for y in -1..=SIZE {
+                    assert_eq!(points.contains(&[x, y]), a.contains(&[x, y]));
+                }
+
+                for axis in 0..2 {
+                    let (below, above) = a.split(axis, x);
+                    let below = below.map_or_else(HashSet::new, |below| self::points(&below));
+                    let above = above.map_or_else(HashSet::new, |above| self::points(&above));
+
+                    assert!(below.iter().all(|p| p[axis] < x));
+                    assert!(above.iter().all(|p| p[axis] >= x));
+                    assert_eq!(points, &below | &above);
+                }
+            }
+
+            if !a.is_empty() {
+                assert_eq!(Some(a), Cuboid::bounding(points.iter().copied()));
+            }
+        }
+    }
+
+    #[test]
+    fn pairs_of_cuboids() {
+        let cuboids = cuboids();
+
+        for a in &cuboids {
+            for b in &cuboids {
+                let (pa, pb) = (points(a), points(b));
+                let intersection = a.intersection(b);
+
+                assert_eq!(
+                    &pa & &pb,
+                    intersection.map_or_else(HashSet::new, |c| points(&c))
+                );
+                assert_eq!(!(&pa & &pb).is_empty(), a.intersects(b));
+
+                let pieces = a.subtract(b);
+                let covered: HashSet<_> = pieces.iter().flat_map(points).collect();
+
+                assert!(pieces.len() <= 4 && pieces.iter().all(|piece| !piece.is_empty()));
+                assert_eq!(
+                    covered.len() as i32,
+                    pieces.iter().map(Cuboid::volume).sum::<i32>()
+                );
+                assert_eq!(&pa - &pb, covered);
+                assert_eq!((&pa | &pb).len() as i32, union_volume([*a, *b]));
+            }
+        }
+    }
+
+    #[test]
+    fn many_cuboids() {
+        let cuboids: Vec<Cuboid<i64, 3>> = (0..20)
+            .map(|i| {
+                let corner = [i * 7 % 11, i * 5 % 13, i * 3 % 7];
+
+                Cuboid::new(corner, corner.map(|c| c + 4 + i % 3))
+            })
+            .collect();
+        let points: HashSet<[i64; 3]> = cuboids
+            .iter()
+            .flat_map(|c| {
+                c.range(0).flat_map(move |x| {
+                    c.range(1)
+                        .flat_map(move |y| c.range(2).map(move |z| [x, y, z]))
+                })
+            })
+            .collect();
+
+        assert_eq!(points.len() as i64, union_volume(cuboids.iter().copied()));
+        assert_eq!(0, union_volume(Vec::<Cuboid<u8, 4>>::new()));
+
+        let c = Cuboid::inclusive([5u8, 1], [2, 3]);
+
+        assert_eq!(Cuboid::new([2, 1], [6, 4]), c);
+        assert_eq!(Cuboid::new([1, 0], [7, 5]), c.expand(1));
+        assert_eq!(Cuboid::new([1], [4]), c.project([1]));
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+/// Undirected, unweighted graph whose nodes are interned names.
+///
+/// Nodes are numbered in insertion order; parallel edges and self loops are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct UndirectedGraph {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+/// Two-way partition of a graph, and the number of edges crossing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub weight: usize,
+    pub side: Vec<usize>,
+}
+
+impl UndirectedGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of `name`, adding it as an isolated node if it is new.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.index.get(name) {
+            return index;
+        }
+
+        self.names.push(name.to_owned());
+        self.adjacency.push(vec![]);
+        self.index.insert(name.to_owned(), self.names.len() - 1);
+
+        self.names.len() - 1
+    }
+
+    pub fn add_edge(&mut self, a: &str, b: &str) {
+        let a = self.intern(a);
+        let b = self.intern(b);
+
+        if a != b && !self.adjacency[a].contains(&b) {
+            self.adjacency[a].push(b);
+            self.adjacency[b].push(a);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(|next| next.len()).sum::<usize>() / 2
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    pub fn name(&self, node: usize) -> Option<&str> {
+        self.names.get(node).map(|name| name.as_str())
+    }
+
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        self.adjacency.get(node).map_or(&[], |next| next)
+    }
+
+    /// Every edge once, as `(a, b)` with `a < b`.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(a, next)| next.iter().filter(move |&&b| a < b).map(move |&b| (a, b)))
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut seen = vec![false; self.len()];
+        let mut components = vec![];
+
+        for root in 0..self.len() {
+            if seen[root] {
+                continue;
+            }
+
+            let mut component = vec![root];
+            let mut queue = VecDeque::from([root]);
+            seen[root] = true;
+
+            while let Some(current) = queue.pop_front() {
+                for &next in &self.adjacency[current] {
+                    if !seen[next] {
+                        seen[next] = true;
+                        component.push(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Edges whose removal disconnects their component (Tarjan's low-link).
+    pub fn bridges(&self) -> Vec<(usize, usize)> {
+        let mut order = vec![usize::MAX; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut bridges = vec![];
+        let mut counter = 0;
+
+        for root in 0..self.len() {
+            if order[root] != usize::MAX {
+                continue;
+            }
+
+            // (node, parent, next neighbor to visit)
+            let mut stack = vec![(root, usize::MAX, 0)];
+            order[root] = counter;
+            low[root] = counter;
+            counter += 1;
+
+            while let Some((node, parent, cursor)) = stack.pop() {
+                if let Some(&next) = self.adjacency[node].get(cursor) {
+                    stack.push((node, parent, cursor + 1));
+
+                    if next == parent {
+                        continue;
+                    }
+
+                    if order[next] == usize::MAX {
+                        order[next] = counter;
+                        low[next] = counter;
+                        counter += 1;
+                        stack.push((next, node, 0));
+                    } else {
+                        low[node] = low[node].min(order[next]);
+                    }
+                } else if parent != usize::MAX {
+                    low[parent] = low[parent].min(low[node]);
+
+                    if low[node] > order[parent] {
+                        bridges.push((parent.min(node), parent.max(node)));
+                    }
+                }
+            }
+        }
+
+        bridges
+    }
+
+    /// Global minimum cut (Stoer–Wagner), `None` for graphs with less than two nodes.
+    pub fn min_cut(&self) -> Option<Cut> {
+        if self.len() < 2 {
+            return None;
+        }
+
+        if let [first, _, ..] = &self.connected_components()[..] {
+            return Some(Cut {
+                weight: 0,
+                side: first.clone(),
+            });
+        }
+
+        let mut weights: Vec<HashMap<usize, usize>> = self
+            .adjacency
+            .iter()
+            .map(|next| next.iter().map(|&n| (n, 1)).collect())
+            .collect();
+        let mut members: Vec<Vec<usize>> = (0..self.len()).map(|node| vec![node]).collect();
+        let mut active: Vec<usize> = (0..self.len()).collect();
+        let mut best: Option<Cut> = None;
+
+        while active.len() > 1 {
+            let mut added = vec![false; self.len()];
+            let mut keys = vec![0; self.len()];
+            let mut queue = BinaryHeap::from([(0, active[0])]);
+            let mut order = Vec::with_capacity(active.len());
+
+            while let Some((key, node)) = queue.pop() {
+                if added[node] || key < keys[node] {
+                    continue;
+                }
+
+                added[node] = true;
+                order.push(node);
+
+                for (&next, &weight) in &weights[node] {
+                    if !added[next] {
+                        keys[next] += weight;
+                        queue.push((keys[next], next));
+                    }
+                }
+            }
+
+            let (s, t) = match order[..] {
+                [.., s, t] => (s, t),
+                _ => unreachable!("the graph is connected"),
+            };
+
+            if best.as_ref().is_none_or(|cut| keys[t] < cut.weight) {
+                best = Some(Cut {
+                    weight: keys[t],
+                    side: members[t].clone(),
+                });
+            }
+
+            for (next, weight) in std::mem::take(&mut weights[t]) {
+                weights[next].remove(&t);
+
+                if next != s {
+                    *weights[s].entry(next).or_default() += weight;
+                    *weights[next].entry(s).or_default() += weight;
+                }
+            }
+
+            let merged = std::mem::take(&mut members[t]);
+            members[s].extend(merged);
+            active.retain(|&node| node != t);
+        }
+
+        best
+    }
+
+    /// Maximum number of edge-disjoint paths between `source` and `sink` (Edmonds–Karp),
+    /// along with the minimum cut separating them: empty when `source` is `sink`.
+    pub fn max_flow(&self, source: usize, sink: usize) -> Cut {
+        if source == sink {
+            return Cut {
+                weight: 0,
+                side: vec![],
+            };
+        }
+
+        let mut flow: HashMap<(usize, usize), isize> = HashMap::new();
+        let residual = |flow: &HashMap<_, _>, a, b| 1 - flow.get(&(a, b)).copied().unwrap_or(0);
+        let mut total = 0;
+
+        loop {
+            let mut parents = vec![None; self.len()];
+            let mut queue = VecDeque::from([source]);
+            parents[source] = Some(source);
+
+            while let Some(current) = queue.pop_front() {
+                if current == sink {
+                    break;
+                }
+
+                for &next in &self.adjacency[current] {
+                    if parents[next].is_none() && residual(&flow, current, next) > 0 {
+                        parents[next] = Some(current);
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            if parents[sink].is_none() {
+                let side = (0..self.len())
+                    .filter(|&node| parents[node].is_some())
+                    .collect();
+
+                return Cut {
+                    weight: total,
+                    side,
+                };
+            }
+
+            let mut node = sink;
+            while let Some(parent) = parents[node].filter(|_| node != source) {
+                *flow.entry((parent, node)).or_default() += 1;
+                *flow.entry((node, parent)).or_default() -= 1;
+                node = parent;
+            }
+
+            total += 1;
+        }
+    }
+}
+
+impl<'a> FromIterator<(&'a str, &'a str)> for UndirectedGraph {
+    fn from_iter<T: IntoIterator<Item = (&'a str, &'a str)>>(iter: T) -> Self {
+        let mut graph = Self::new();
+
+        for (a, b) in iter {
+            graph.add_edge(a, b);
+        }
+
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::*;
+
+    // two triangles joined by the single edge c-d, plus an isolated pair
+    fn sample() -> UndirectedGraph {
+        [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "f"),
+            ("f", "d"),
+            ("x", "y"),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn names(graph: &UndirectedGraph, nodes: &[usize]) -> Vec<String> {
+        let mut names: Vec<_> = nodes
+            .iter()
+            .filter_map(|&node| graph.name(node).map(|name| name.to_owned()))
+            .collect();
+
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn graph_interning() {
+        let mut graph = sample();
+
+        assert_eq!(8, graph.len());
+        assert_eq!(8, graph.edge_count());
+        assert_eq!(Some(3), graph.index_of("d"));
+        assert_eq!(Some("d"), graph.name(3));
+        assert_eq!(3, graph.intern("d"));
+
+        graph.add_edge("b", "a");
+        assert_eq!(8, graph.edge_count());
+    }
+
+    #[test]
+    fn graph_components() {
+        let graph = sample();
+        let components = graph.connected_components();
+
+        assert_eq!(2, components.len());
+        assert_eq!(vec!["x", "y"], names(&graph, &components[1]));
+    }
+
+    #[test]
+    fn graph_bridges() {
+        let graph = sample();
+        let c = graph.index_of("c").unwrap();
+        let d = graph.index_of("d").unwrap();
+        let x = graph.index_of("x").unwrap();
+        let y = graph.index_of("y").unwrap();
+
+        let mut bridges = graph.bridges();
+        bridges.sort();
+
+        assert_eq!(vec![(c, d), (x, y)], bridges);
+    }
+
+    #[test]
+    fn graph_min_cut() {
+        let mut graph = sample();
+        assert_eq!(Some(0), graph.min_cut().map(|cut| cut.weight));
+
+        graph.add_edge("y", "a");
+        graph.add_edge("x", "a");
+        let cut = graph.min_cut().expect("graph has nodes");
+
+        assert_eq!(1, cut.weight);
+        assert_eq!(5, cut.side.len().max(graph.len() - cut.side.len()));
+    }
+
+    #[test]
+    fn graph_max_flow() {
+        let graph = sample();
+        let a = graph.index_of("a").unwrap();
+        let c = graph.index_of("c").unwrap();
+        let f = graph.index_of("f").unwrap();
+        let x = graph.index_of("x").unwrap();
+
+        assert_eq!(2, graph.max_flow(a, c).weight);
+        assert_eq!(0, graph.max_flow(a, x).weight);
+        assert_eq!(0, graph.max_flow(a, a).weight);
+
+        let cut = graph.max_flow(a, f);
+        assert_eq!(1, cut.weight);
+        assert_eq!(vec!["a", "b", "c"], names(&graph, &cut.side));
+    }
+}
@@ -0,0 +1,371 @@
+//! Sets of integers stored as ranges, and piecewise shifts of those sets.
+
+use std::ops::{Range, RangeInclusive};
+
+use crate::number::Integer;
+
+/// Set of integers, as half-open ranges kept sorted, disjoint and not adjacent, so that equal sets
+/// have the same ranges.
+///
+/// ```
+/// use aoc_utils::interval::IntervalSet;
+///
+/// let set: IntervalSet<i64> = [1..4, 3..6, 8..10].into_iter().collect();
+///
+/// assert_eq!(&[1..6, 8..10], set.ranges());
+/// assert_eq!(set, [1..=5, 8..=9].into_iter().collect());
+/// assert_eq!(7, set.len());
+/// assert_eq!(&[6..8], set.complement(0..10).difference(&(0..1).into()).ranges());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn normalized(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |len, range| len + (range.end - range.start))
+    }
+
+    /// Smallest integer of the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// Largest integer of the set.
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: impl Into<Self>) {
+        *self = self.union(&range.into());
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = vec![];
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The integers of `within` that aren't in the set.
+    pub fn complement(&self, within: Range<T>) -> Self {
+        let mut start = within.start;
+        let mut ranges = vec![];
+
+        for range in &self.ranges {
+            if range.start >= within.end {
+                break;
+            }
+
+            if start < range.start {
+                ranges.push(start..range.start);
+            }
+
+            start = start.max(range.end);
+        }
+
+        if start < within.end {
+            ranges.push(start..within.end);
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.min(), self.ranges.last()) {
+            (Some(start), Some(last)) => self.intersection(&other.complement(start..last.end)),
+            _ => Self::new(),
+        }
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+}
+
+impl<T: Integer> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalized(vec![range])
+    }
+}
+
+/// The end of the range is excluded from the set with `+ 1`, so it can't be `T::MAX`.
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+
+        Self::normalized(vec![start..end + T::ONE])
+    }
+}
+
+impl<T: Integer, R: Into<IntervalSet<T>>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().flat_map(|set| set.into().ranges).collect())
+    }
+}
+
+/// Piecewise shifts of ranges of integers, those outside of the ranges mapping to themselves.
+///
+/// ```
+/// use aoc_utils::interval::{IntervalSet, RangeMap};
+///
+/// // seed-to-soil map: 50 98 2, 52 50 48
+/// let map: RangeMap<i64> = [(98..100, 50), (50..98, 52)].into_iter().collect();
+///
+/// let seeds: IntervalSet<i64> = [0..10, 50..52].into_iter().collect();
+///
+/// assert_eq!(81, map.get(79));
+/// assert_eq!(&[0..10, 52..54], map.apply(&seeds).ranges());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    /// Source ranges with the start of their destinations, in order of precedence.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self { pieces: vec![] }
+    }
+}
+
+impl<T: Integer> RangeMap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `source` to the range of the same length starting at `destination`. Where the sources
+    /// overlap, the one inserted first is used.
+    pub fn insert(&mut self, source: Range<T>, destination: T) {
+        self.pieces.push((source, destination));
+    }
+
+    pub fn get(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(source, destination)| {
+                *destination + (value - source.start)
+            })
+    }
+
+    /// Maps every integer of the set.
+    pub fn apply(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut rest = set.clone();
+        let mut mapped = vec![];
+
+        for (source, destination) in &self.pieces {
+            let source = IntervalSet::from(source.clone());
+            let Some(offset) = source.min() else {
+                continue;
+            };
+
+            mapped.extend(rest.intersection(&source).ranges.into_iter().map(|range| {
+                *destination + (range.start - offset)..*destination + (range.end - offset)
+            }));
+            rest = rest.difference(&source);
+        }
+
+        mapped.extend(rest.ranges);
+
+        IntervalSet::normalized(mapped)
+    }
+}
+
+impl<T: Integer> FromIterator<(Range<T>, T)> for RangeMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(iter: I) -> Self {
+        Self {
+            pieces: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::*;
+
+    /// Every subset of `0..UNIVERSE` is checked against the same set as a bit mask.
+    const UNIVERSE: u32 = 7;
+
+    fn set(mask: u32) -> IntervalSet<u32> {
+        (0..UNIVERSE)
+            .filter(|i| mask & 1 << i != 0)
+            .map(|i| i..i + 1)
+            .collect()
+    }
+
+    fn mask(set: &IntervalSet<u32>) -> u32 {
+        set.ranges()
+            .iter()
+            .flat_map(|range| range.clone())
+            .fold(0, |mask, i| mask | 1 << i)
+    }
+
+    fn normalized(set: &IntervalSet<u32>) -> bool {
+        set.ranges().iter().all(|range| range.start < range.end)
+            && set.ranges().windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    fn sets() -> impl Iterator<Item = (u32, IntervalSet<u32>)> {
+        (0..1 << UNIVERSE).map(|mask| (mask, set(mask)))
+    }
+
+    fn ranges() -> impl Iterator<Item = Range<u32>> {
+        (0..=UNIVERSE).flat_map(|start| (start..=UNIVERSE).map(move |end| start..end))
+    }
+
+    #[test]
+    fn single_sets() {
+        for (m, a) in sets() {
+            assert!(normalized(&a));
+            assert_eq!(m, mask(&a));
+            assert_eq!(m.count_ones(), a.len());
+            assert_eq!(m == 0, a.is_empty());
+            assert_eq!((m != 0).then(|| m.trailing_zeros()), a.min());
+            assert_eq!((m != 0).then(|| 31 - m.leading_zeros()), a.max());
+
+            for i in 0..=UNIVERSE {
+                assert_eq!(m & 1 << i != 0, a.contains(i));
+            }
+
+            for within in ranges() {
+                let complement = a.complement(within.clone());
+                let within_mask = mask(&within.into());
+
+                assert!(normalized(&complement));
+                assert_eq!(!m & within_mask, mask(&complement));
+            }
+        }
+    }
+
+    #[test]
+    fn pairs_of_sets() {
+        for (m, a) in sets() {
+            for (n, b) in sets() {
+                let (union, intersection, difference) =
+                    (a.union(&b), a.intersection(&b), a.difference(&b));
+
+                assert!([&union, &intersection, &difference]
+                    .into_iter()
+                    .all(normalized));
+                assert_eq!(m | n, mask(&union));
+                assert_eq!(m & n, mask(&intersection));
+                assert_eq!(m & !n, mask(&difference));
+                assert_eq!(m & !n == 0, a.is_subset(&b));
+                assert_eq!(m & n == 0, a.is_disjoint(&b));
+
+                let mut inserted = a.clone();
+
+                b.ranges()
+                    .iter()
+                    .for_each(|range| inserted.insert(range.clone()));
+                assert_eq!(union, inserted);
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn conversions() {
+        assert_eq!(IntervalSet::from(2..5), IntervalSet::from(2..=4));
+        assert!(IntervalSet::from(3..=2).is_empty());
+        assert!(IntervalSet::from(3..1).is_empty());
+        assert_eq!(
+            IntervalSet::from(0..6),
+            [0..=1, 4..=5, 2..=3].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn range_maps() {
+        const UNIVERSE: u32 = 5;
+
+        let pieces = || {
+            (0..UNIVERSE).flat_map(|start| {
+                (start + 1..=UNIVERSE).flat_map(move |end| {
+                    (0..UNIVERSE).map(move |destination| (start..end, destination))
+                })
+            })
+        };
+        let maps = pieces().flat_map(|first| {
+            pieces().map(move |second| RangeMap::from_iter([first.clone(), second]))
+        });
+
+        for map in maps.chain([RangeMap::new()]) {
+            for (m, a) in sets().filter(|(m, _)| m >> UNIVERSE == 0) {
+                let expected = (0..UNIVERSE)
+                    .filter(|i| m & 1 << i != 0)
+                    .fold(0, |mask, i| mask | 1 << map.get(i));
+                let mapped = map.apply(&a);
+
+                assert!(normalized(&mapped));
+                assert_eq!(expected, mask(&mapped), "{map:?} on {a:?}");
+            }
+        }
+    }
+}
@@ -1,6 +1,11 @@
 pub mod collections;
+pub mod cuboid;
+pub mod graph;
+pub mod interval;
+pub mod number;
 pub mod pathfinding;
 pub mod pointer;
+pub mod polygon;
 
 pub fn is_flag(flag: &str) -> bool {
     std::env::args().any(|arg| arg.as_str() == flag)
@@ -10,6 +15,44 @@ pub fn lines_to_owned(input: &str) -> Vec<String> {
     input.lines().map(|line| line.to_owned()).collect()
 }
 
+/// Splits the input on blank lines, with either `\n` or `\r\n` line endings.
+///
+/// Each paragraph is a slice of `input` without its last line ending; consecutive blank lines
+/// don't yield empty paragraphs.
+///
+/// ```
+/// use aoc_utils::paragraphs;
+///
+/// assert_eq!(vec!["1\n2", "3"], paragraphs("1\n2\n\n\n3\n").collect::<Vec<_>>());
+/// assert_eq!(vec!["1\r\n2", "3"], paragraphs("1\r\n2\r\n\r\n3").collect::<Vec<_>>());
+/// ```
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.split_inclusive('\n');
+    let mut offset = 0;
+    let mut paragraph: Option<(usize, usize)> = None;
+
+    std::iter::from_fn(move || {
+        for line in lines.by_ref() {
+            let start = offset;
+            let content = line.trim_end_matches(['\n', '\r']);
+
+            offset += line.len();
+
+            if content.is_empty() {
+                if let Some((start, end)) = paragraph.take() {
+                    return Some(&input[start..end]);
+                }
+            } else {
+                let (_, end) = paragraph.get_or_insert((start, start));
+
+                *end = start + content.len();
+            }
+        }
+
+        paragraph.take().map(|(start, end)| &input[start..end])
+    })
+}
+
 pub fn neighbors(width: usize, height: usize, x: usize, y: usize) -> Vec<(usize, usize)> {
     [
         (y > 0).then(|| (x, y - 1)),
@@ -22,25 +65,6 @@ pub fn neighbors(width: usize, height: usize, x: usize, y: usize) -> Vec<(usize,
     .collect()
 }
 
-pub fn lcm(x: usize, y: usize) -> usize {
-    x * y / gcd(x, y)
-}
-
-pub fn gcd(x: usize, y: usize) -> usize {
-    let mut min = x.min(y);
-    let mut max = x.max(y);
-
-    loop {
-        let rem = max % min;
-        if rem == 0 {
-            return min;
-        }
-
-        max = min;
-        min = rem;
-    }
-}
-
 pub fn index(value: isize, min: isize, max: isize) -> usize {
     let size_y = max.abs_diff(min) as isize;
 
@@ -50,6 +74,8 @@ pub fn index(value: isize, min: isize, max: isize) -> usize {
 // https://doc.rust-lang.org/std/collections/binary_heap/index.html
 pub mod dijkstra {
     use super::neighbors;
+    use crate::pathfinding::Cost;
+    use std::cmp::Reverse;
     use std::collections::BinaryHeap;
 
     #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -66,47 +92,47 @@ pub mod dijkstra {
         pub height: usize,
     }
 
-    pub fn solve(
+    pub fn solve<C: Cost>(
         start: &Coord,
         boundaries: &Boundaries,
-        get_cost: impl Fn(&Coord, &Coord) -> Option<i32>,
+        get_cost: impl Fn(&Coord, &Coord) -> Option<C>,
         is_target: impl Fn(&Coord) -> bool,
-    ) -> Option<(Vec<Coord>, i32)> {
-        let mut dist: Vec<Vec<_>> = (0..boundaries.height)
-            .map(|_| (0..boundaries.width).map(|_| i32::MAX).collect())
+    ) -> Option<(Vec<Coord>, C)> {
+        let mut dist: Vec<Vec<Option<C>>> = (0..boundaries.height)
+            .map(|_| (0..boundaries.width).map(|_| None).collect())
             .collect();
         let mut heap = BinaryHeap::new();
 
-        heap.push((0, start.x, start.y));
-        dist[start.y][start.x] = 0;
+        heap.push(Reverse((C::zero(), start.x, start.y)));
+        dist[start.y][start.x] = Some(C::zero());
 
-        while let Some((cost, x, y)) = heap.pop() {
+        while let Some(Reverse((cost, x, y))) = heap.pop() {
             let current = Coord { x, y };
             if is_target(&current) {
-                return Some((get_path(&dist, start, &current), -cost));
+                return Some((get_path(&dist, start, &current), cost));
             }
 
-            if cost > dist[current.y][current.x] {
+            if dist[current.y][current.x].is_some_and(|best| cost > best) {
                 continue;
             }
 
             for (x, y) in neighbors(boundaries.width, boundaries.height, current.x, current.y) {
                 let neighbor = Coord { x, y };
                 let next_cost = match get_cost(&current, &neighbor) {
-                    Some(new_cost) => -cost + new_cost,
+                    Some(new_cost) => cost + new_cost,
                     None => continue,
                 };
 
-                if next_cost < dist[neighbor.y][neighbor.x] {
-                    heap.push((-next_cost, neighbor.x, neighbor.y));
-                    dist[neighbor.y][neighbor.x] = next_cost;
+                if dist[neighbor.y][neighbor.x].is_none_or(|best| next_cost < best) {
+                    heap.push(Reverse((next_cost, neighbor.x, neighbor.y)));
+                    dist[neighbor.y][neighbor.x] = Some(next_cost);
                 }
             }
         }
         None
     }
 
-    fn get_path(dist: &[Vec<i32>], start: &Coord, target: &Coord) -> Vec<Coord> {
+    fn get_path<C: Cost>(dist: &[Vec<Option<C>>], start: &Coord, target: &Coord) -> Vec<Coord> {
         let mut path = vec![];
 
         let mut current = *target;
@@ -115,10 +141,11 @@ pub mod dijkstra {
             path.push(current);
 
             current = match neighbors(dist[0].len(), dist.len(), current.x, current.y)
-                .iter()
-                .min_by(|&&(xa, ya), &&(xb, yb)| dist[ya][xa].cmp(&dist[yb][xb]))
+                .into_iter()
+                .filter_map(|(x, y)| Some(((x, y), dist[y][x]?)))
+                .min_by_key(|&(_, cost)| cost)
             {
-                Some(&(x, y)) => Coord { x, y },
+                Some(((x, y), _)) => Coord { x, y },
                 _ => panic!(),
             }
         }
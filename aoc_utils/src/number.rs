@@ -0,0 +1,319 @@
+//! Number theory on any primitive integer, up to `u128`.
+//!
+//! [`gcd`] and [`lcm`] take any value, `T::MIN` included, while [`extended_gcd`] needs values
+//! whose magnitude fits in `T`.
+//!
+//! Modular functions take moduli greater than zero and work on residues in `0..m`, without
+//! overflowing on products of residues, so `u128` moduli are supported too.
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+/// Integers with negative values, for Bézout coefficients.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+        }
+    )*};
+}
+
+integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// The magnitude of `x`, `None` when it doesn't fit in `T`, for `T::MIN`.
+fn abs<T: Integer>(x: T) -> Option<T> {
+    match x < T::ZERO {
+        true => x.checked_neg(),
+        false => Some(x),
+    }
+}
+
+/// Greatest common divisor, always positive, `gcd(0, 0)` being 0.
+///
+/// Panics when it doesn't fit in `T`: only `gcd(T::MIN, 0)` and `gcd(T::MIN, T::MIN)` for signed
+/// types.
+pub fn gcd<T: Integer>(x: T, y: T) -> T {
+    let (mut x, mut y) = (x, y);
+
+    // signed remainders keep T::MIN in range, T::MIN % -1 being the only overflow
+    while y != T::ZERO {
+        (x, y) = (y, x.checked_rem(y).unwrap_or(T::ZERO));
+    }
+
+    abs(x).expect("the gcd of T::MIN and 0 overflows")
+}
+
+/// Least common multiple, 0 when either is 0, `None` on overflow.
+pub fn lcm<T: Integer>(x: T, y: T) -> Option<T> {
+    match x == T::ZERO || y == T::ZERO {
+        true => Some(T::ZERO),
+        false => {
+            let (x, y) = (abs(x)?, abs(y)?);
+
+            (x / gcd(x, y)).checked_mul(y)
+        }
+    }
+}
+
+/// Least common multiple of all the values, 1 when there are none, `None` on overflow.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |multiple, value| lcm(multiple, value))
+}
+
+/// Extended Euclid: `(g, a, b)` such that `x * a + y * b = g`, the [`gcd`] of `x` and `y`.
+pub fn extended_gcd<T: Signed>(x: T, y: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (x, y);
+    let (mut a0, mut a1) = (T::ONE, T::ZERO);
+    let (mut b0, mut b1) = (T::ZERO, T::ONE);
+
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+
+        (r0, r1) = (r1, r0 - q * r1);
+        (a0, a1) = (a1, a0 - q * a1);
+        (b0, b1) = (b1, b0 - q * b1);
+    }
+
+    match r0 < T::ZERO {
+        true => (-r0, -a0, -b0),
+        false => (r0, a0, b0),
+    }
+}
+
+/// The residue of `x` modulo `m`, in `0..m`.
+pub fn residue<T: Integer>(x: T, m: T) -> T {
+    let r = x % m;
+
+    match r < T::ZERO {
+        true => r + m,
+        false => r,
+    }
+}
+
+fn add_mod<T: Integer>(x: T, y: T, m: T) -> T {
+    match x >= m - y {
+        true => x - (m - y),
+        false => x + y,
+    }
+}
+
+fn sub_mod<T: Integer>(x: T, y: T, m: T) -> T {
+    match x >= y {
+        true => x - y,
+        false => x + (m - y),
+    }
+}
+
+/// `x * y` modulo `m`, without overflowing.
+pub fn mul_mod<T: Integer>(x: T, y: T, m: T) -> T {
+    let (mut x, mut y) = (residue(x, m), residue(y, m));
+
+    if let Some(product) = x.checked_mul(y) {
+        return product % m;
+    }
+
+    let two = T::ONE + T::ONE;
+    let mut product = T::ZERO;
+
+    while y > T::ZERO {
+        if y % two == T::ONE {
+            product = add_mod(product, x, m);
+        }
+
+        x = add_mod(x, x, m);
+        y = y / two;
+    }
+
+    product
+}
+
+/// `base` to the power of `exp` modulo `m`, by squaring.
+pub fn modpow<T: Integer>(base: T, mut exp: T, m: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut base = residue(base, m);
+    let mut power = T::ONE % m;
+
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            power = mul_mod(power, base, m);
+        }
+
+        base = mul_mod(base, base, m);
+        exp = exp / two;
+    }
+
+    power
+}
+
+/// Inverse of `x` modulo `m`, `None` when they aren't coprime.
+pub fn modinv<T: Integer>(x: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
+    }
+
+    // extended Euclid, keeping only the coefficient of x, as a residue modulo m
+    let (mut r0, mut r1) = (m, residue(x, m));
+    let (mut a0, mut a1) = (T::ZERO, T::ONE % m);
+
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+
+        (r0, r1) = (r1, r0 % r1);
+        (a0, a1) = (a1, sub_mod(a0, mul_mod(q, a1, m), m));
+    }
+
+    (r0 == T::ONE).then_some(a0)
+}
+
+/// Chinese Remainder Theorem: the `(x, m)` such that `x ≡ r (mod n)` for every `(r, n)`, where `m`
+/// is the [`lcm`] of the moduli and `x` is in `0..m`.
+///
+/// The moduli need not be coprime, `None` when the congruences disagree, a modulus isn't positive
+/// or their `lcm` overflows.
+///
+/// ```
+/// use aoc_utils::number::crt;
+///
+/// assert_eq!(Some((23, 105)), crt([(2, 3), (3, 5), (2, 7)]));
+/// assert_eq!(Some((10, 12)), crt([(4, 6), (2, 4)]));
+/// assert_eq!(None, crt([(1, 6), (2, 4)]));
+/// ```
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::ZERO, T::ONE), |(x, m), (r, n)| {
+            if n <= T::ZERO {
+                return None;
+            }
+
+            let g = gcd(m, n);
+            let diff = sub_mod(residue(r, n), residue(x, n), n);
+
+            if diff % g != T::ZERO {
+                return None;
+            }
+
+            // x + m * t solves both, with t = diff / g * (m / g)^-1 modulo n / g
+            let n = n / g;
+            let t = mul_mod(diff / g, modinv(m / g, n)?, n);
+            let multiple = m.checked_mul(n)?;
+
+            Some((add_mod(x, m * t, multiple), multiple))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::number::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(6, gcd(-12, 18));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(0, gcd(0u8, 0));
+        assert_eq!(2, gcd(i64::MIN, 6));
+        assert_eq!(1, gcd(-1, i64::MIN));
+        assert_eq!(1, gcd(i8::MIN, -1));
+        assert_eq!(1 << 62, gcd(i64::MIN, 1 << 62));
+
+        assert_eq!(Some(36), lcm(12, 18));
+        assert_eq!(Some(0), lcm(0, 7));
+        assert_eq!(None, lcm(200u8, 3));
+        assert_eq!(Some(u64::MAX), lcm(u64::MAX, u64::MAX));
+        assert_eq!(None, lcm(i64::MIN, 1));
+        assert_eq!(Some(0), lcm(i64::MIN, 0));
+
+        assert_eq!(Some(2520u64), lcm_all(1..=10));
+        assert_eq!(Some(1), lcm_all(Vec::<u32>::new()));
+        assert_eq!(None, lcm_all(1..=100u128));
+    }
+
+    #[test]
+    fn bezout() {
+        for (x, y) in [(240, 46), (-240, 46), (17, 0), (0, -5), (7, 13)] {
+            let (g, a, b) = extended_gcd(x, y);
+
+            assert_eq!(gcd(x, y), g);
+            assert_eq!(g, x * a + y * b);
+        }
+    }
+
+    #[test]
+    fn modular() {
+        assert_eq!(Some(4), modinv(3, 11));
+        assert_eq!(Some(7), modinv(-3i64, 11));
+        assert_eq!(None, modinv(4, 8));
+        assert_eq!(Some(0), modinv(5, 1));
+
+        assert_eq!(445, modpow(4, 13, 497));
+        assert_eq!(0, modpow(3, 0, 1));
+        assert_eq!(1, modpow(2u8, 0, 7));
+
+        let p = u128::MAX - 158; // largest prime below 2^128
+        let x = u128::MAX / 3;
+
+        assert_eq!(1, modpow(x, p - 1, p));
+        assert_eq!(1, mul_mod(x, modinv(x, p).expect("coprime"), p));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(Some((0, 1)), crt(Vec::<(i32, i32)>::new()));
+        assert_eq!(Some((1, 4)), crt([(-3, 4)]));
+        assert_eq!(None, crt([(1, 0)]));
+        assert_eq!(
+            Some((1068781, 3162341)),
+            crt([(0, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)])
+        );
+
+        let moduli = [u64::MAX - 58, u64::MAX - 82]; // two primes below 2^64
+        let (x, m) = crt(moduli.map(|n| (u128::from(n - 1), u128::from(n)))).expect("coprime");
+
+        assert_eq!(u128::from(moduli[0]) * u128::from(moduli[1]), m);
+        assert_eq!(m - 1, x);
+        assert_eq!(None, crt([(0u128, u128::MAX), (0, u128::MAX - 1)]));
+    }
+}
@@ -1,5 +1,7 @@
-use std::collections::HashMap;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
 use std::hash::Hash;
+use std::ops::Add;
 
 pub trait Graph<N> {
     fn start(&self) -> Option<N>;
@@ -9,115 +11,1105 @@ pub trait Graph<N> {
     fn estimated_size(&self) -> usize {
         0
     }
+
+    /// Every node the search may begin from. Defaults to [`Graph::start`].
+    fn starts(&self) -> Vec<N> {
+        self.start().into_iter().collect()
+    }
+}
+
+/// The closest target found by a multi-source search, and the source it was reached from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reached<N, C> {
+    pub source: N,
+    pub target: N,
+    pub cost: C,
+}
+
+/// Edge weight accumulated by the weighted solvers.
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    fn zero() -> Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(impl Cost for $t {
+            fn zero() -> Self {
+                0
+            }
+        })*
+    };
+}
+
+impl_cost!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// `f64` totally ordered with [`f64::total_cmp`], to use fractional weights as a [`Cost`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OrdF64(pub f64);
+
+impl PartialEq for OrdF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrdF64 {}
+
+impl PartialOrd for OrdF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrdF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl Add for OrdF64 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Cost for OrdF64 {
+    fn zero() -> Self {
+        Self(0.)
+    }
+}
+
+/// Weighted graph over the nodes kept by [`compress`], numbered in discovery order.
+#[derive(Debug, Clone)]
+pub struct Compressed<N, C> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, C)>>,
+    starts: Vec<usize>,
+    targets: Vec<bool>,
+}
+
+impl<N, C> Compressed<N, C>
+where
+    N: Copy + Hash + Eq,
+    C: Cost,
+{
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn node(&self, index: usize) -> Option<N> {
+        self.nodes.get(index).copied()
+    }
+
+    pub fn index_of(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+
+    pub fn edges(&self, index: usize) -> &[(usize, C)] {
+        self.edges.get(index).map_or(&[], |edges| edges)
+    }
+
+    pub fn cost(&self, from: usize, to: usize) -> Option<C> {
+        self.edges(from)
+            .iter()
+            .find_map(|(next, cost)| (*next == to).then_some(*cost))
+    }
+
+    /// Shortest cost between every pair of kept nodes (Floyd–Warshall).
+    pub fn distance_matrix(&self) -> Vec<Vec<Option<C>>> {
+        let size = self.len();
+        let mut matrix = vec![vec![None; size]; size];
+
+        for (from, edges) in self.edges.iter().enumerate() {
+            matrix[from][from] = Some(C::zero());
+
+            for &(to, cost) in edges {
+                matrix[from][to] = Some(cost);
+            }
+        }
+
+        for k in 0..size {
+            for i in 0..size {
+                for j in 0..size {
+                    if let (Some(a), Some(b)) = (matrix[i][k], matrix[k][j]) {
+                        if matrix[i][j].is_none_or(|best| a + b < best) {
+                            matrix[i][j] = Some(a + b);
+                        }
+                    }
+                }
+            }
+        }
+
+        matrix
+    }
+}
+
+impl<N, C> Graph<usize> for Compressed<N, C> {
+    fn start(&self) -> Option<usize> {
+        self.starts.first().copied()
+    }
+
+    fn adjacent(&self, node: &usize) -> Option<Vec<usize>> {
+        self.edges
+            .get(*node)
+            .map(|edges| edges.iter().map(|(next, _)| *next).collect())
+    }
+
+    fn is_target(&self, node: &usize) -> bool {
+        self.targets.get(*node).is_some_and(|&target| target)
+    }
+
+    fn estimated_size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn starts(&self) -> Vec<usize> {
+        self.starts.clone()
+    }
+}
+
+/// Replaces every walk between two kept nodes by a single weighted edge.
+///
+/// Starts are always kept. Only the cheapest walk between two kept nodes is retained.
+pub fn compress<G, N, C>(
+    graph: &G,
+    get_cost: impl Fn(&N, &N) -> Option<C>,
+    keep: impl Fn(&N) -> bool,
+) -> Compressed<N, C>
+where
+    N: Copy + Hash + Eq + Ord,
+    G: Graph<N>,
+    C: Cost,
+{
+    let starts = graph.starts();
+    let mut nodes = starts.clone();
+    let mut index: HashMap<_, _> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut edges = vec![];
+
+    while let Some(&from) = nodes.get(edges.len()) {
+        let mut found = vec![];
+        let mut queue = BinaryHeap::from([Reverse((C::zero(), from))]);
+        let mut cost_lookup = HashMap::from([(from, C::zero())]);
+
+        while let Some(Reverse((cost, current))) = queue.pop() {
+            if cost_lookup.get(&current).is_some_and(|&best| cost > best) {
+                continue;
+            }
+
+            if current != from && (index.contains_key(&current) || keep(&current)) {
+                let next = *index.entry(current).or_insert_with(|| {
+                    nodes.push(current);
+                    nodes.len() - 1
+                });
+
+                found.push((next, cost));
+                continue;
+            }
+
+            for next in graph.adjacent(&current).into_iter().flatten() {
+                let next_cost = match get_cost(&current, &next) {
+                    None => continue,
+                    Some(new_cost) => cost + new_cost,
+                };
+
+                if cost_lookup.get(&next).is_none_or(|&best| next_cost < best) {
+                    queue.push(Reverse((next_cost, next)));
+                    cost_lookup.insert(next, next_cost);
+                }
+            }
+        }
+
+        edges.push(found);
+    }
+
+    Compressed {
+        starts: (0..starts.len()).collect(),
+        targets: nodes.iter().map(|node| graph.is_target(node)).collect(),
+        nodes,
+        index,
+        edges,
+    }
+}
+
+fn get_path<N>(history: &HashMap<N, Option<N>>, mut target: N) -> Option<Vec<N>>
+where
+    N: Copy + Hash + Eq,
+{
+    let mut path = vec![target];
+
+    loop {
+        match history.get(&target)? {
+            Some(parent) => {
+                path.push(*parent);
+                target = *parent;
+            }
+            None => return Some(path.into_iter().rev().collect()),
+        }
+    }
+}
+
+fn get_source<N>(history: &HashMap<N, Option<N>>, mut node: N) -> N
+where
+    N: Copy + Hash + Eq,
+{
+    while let Some(Some(parent)) = history.get(&node) {
+        node = *parent;
+    }
+    node
+}
+
+pub mod dijkstra {
+    use crate::pathfinding::{get_path, get_source, Cost, Reached};
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap, HashSet};
+    use std::fmt::Debug;
+    use std::hash::Hash;
+
+    pub fn solve<G, N, C>(
+        graph: &G,
+        get_cost: impl Fn(&N, &N) -> Option<C>,
+    ) -> Option<(C, impl Fn() -> Option<Vec<N>>)>
+    where
+        N: Copy + Hash + Eq + Ord + Debug,
+        G: super::Graph<N>,
+        C: Cost,
+    {
+        let (reached, get_path) = solve_from(graph, graph.starts(), get_cost)?;
+
+        Some((reached.cost, get_path))
+    }
+
+    /// Searches from every node in `starts` at once and stops at the closest target.
+    #[allow(clippy::type_complexity)]
+    pub fn solve_from<G, N, C>(
+        graph: &G,
+        starts: impl IntoIterator<Item = N>,
+        get_cost: impl Fn(&N, &N) -> Option<C>,
+    ) -> Option<(Reached<N, C>, impl Fn() -> Option<Vec<N>>)>
+    where
+        N: Copy + Hash + Eq + Ord + Debug,
+        G: super::Graph<N>,
+        C: Cost,
+    {
+        let mut queue = BinaryHeap::new();
+        let mut cost_lookup = HashMap::with_capacity(graph.estimated_size());
+        let mut history = HashMap::new();
+
+        for start in starts {
+            queue.push(Reverse((C::zero(), start)));
+            cost_lookup.insert(start, C::zero());
+            history.insert(start, None);
+        }
+
+        while let Some(Reverse((cost, current))) = queue.pop() {
+            if graph.is_target(&current) {
+                let reached = Reached {
+                    source: get_source(&history, current),
+                    target: current,
+                    cost,
+                };
+
+                return Some((reached, move || get_path(&history, current)));
+            }
+
+            if cost_lookup.get(&current).is_some_and(|&best| cost > best) {
+                continue;
+            }
+
+            for next in graph.adjacent(&current)? {
+                let next_cost = match get_cost(&current, &next) {
+                    None => continue,
+                    Some(new_cost) => cost + new_cost,
+                };
+
+                if cost_lookup.get(&next).is_none_or(|&best| next_cost < best) {
+                    queue.push(Reverse((next_cost, next)));
+                    cost_lookup.insert(next, next_cost);
+                    history.insert(next, Some(current));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Every shortest path from the starts to the closest targets, as found by [`solve_all`].
+    #[derive(Debug, Clone)]
+    pub struct ShortestPaths<N, C> {
+        cost: C,
+        starts: HashSet<N>,
+        targets: Vec<N>,
+        parents: HashMap<N, Vec<N>>,
+        counts: HashMap<N, u128>,
+    }
+
+    impl<N, C> ShortestPaths<N, C>
+    where
+        N: Copy + Hash + Eq,
+        C: Cost,
+    {
+        pub fn cost(&self) -> C {
+            self.cost
+        }
+
+        /// targets reached at [`Self::cost`]
+        pub fn targets(&self) -> &[N] {
+            &self.targets
+        }
+
+        pub fn count_shortest_paths(&self) -> u128 {
+            self.targets
+                .iter()
+                .filter_map(|target| self.counts.get(target))
+                .sum()
+        }
+
+        pub fn nodes_on_any_shortest_path(&self) -> HashSet<N> {
+            let mut nodes: HashSet<N> = self.targets.iter().copied().collect();
+            let mut queue = self.targets.clone();
+
+            while let Some(node) = queue.pop() {
+                for &parent in self.parents.get(&node).into_iter().flatten() {
+                    if nodes.insert(parent) {
+                        queue.push(parent);
+                    }
+                }
+            }
+
+            nodes
+        }
+
+        /// lazily walks every shortest path, from a start to a target
+        pub fn paths(&self) -> impl Iterator<Item = Vec<N>> + '_ {
+            let mut stack: Vec<Vec<N>> = self.targets.iter().map(|&target| vec![target]).collect();
+
+            std::iter::from_fn(move || {
+                while let Some(path) = stack.pop() {
+                    let last = *path.last()?;
+
+                    if self.starts.contains(&last) {
+                        return Some(path.into_iter().rev().collect());
+                    }
+
+                    for &parent in self.parents.get(&last).into_iter().flatten() {
+                        let mut next = path.clone();
+
+                        next.push(parent);
+                        stack.push(next);
+                    }
+                }
+
+                None
+            })
+        }
+    }
+
+    /// Like [`solve`], but keeps every equal-cost predecessor and every target reached at the
+    /// minimal cost, from any of the [`Graph::starts`](super::Graph::starts).
+    ///
+    /// Edge costs must be strictly positive, zero-cost cycles would make the count infinite.
+    pub fn solve_all<G, N, C>(
+        graph: &G,
+        get_cost: impl Fn(&N, &N) -> Option<C>,
+    ) -> Option<ShortestPaths<N, C>>
+    where
+        N: Copy + Hash + Eq + Ord,
+        G: super::Graph<N>,
+        C: Cost,
+    {
+        let starts: HashSet<N> = graph.starts().into_iter().collect();
+        let mut queue = BinaryHeap::new();
+        let mut cost_lookup = HashMap::with_capacity(graph.estimated_size());
+        let mut parents: HashMap<N, Vec<N>> = HashMap::new();
+        let mut counts = HashMap::new();
+        let mut settled = HashSet::new();
+        let mut found: Option<(C, Vec<N>)> = None;
+
+        for &start in &starts {
+            queue.push(Reverse((C::zero(), start)));
+            cost_lookup.insert(start, C::zero());
+        }
+
+        while let Some(Reverse((cost, current))) = queue.pop() {
+            if found.as_ref().is_some_and(|(best, _)| cost > *best) {
+                break;
+            }
+
+            if !settled.insert(current) {
+                continue;
+            }
+
+            let count = match parents.get(&current) {
+                None => 1,
+                Some(from) => from.iter().filter_map(|parent| counts.get(parent)).sum(),
+            };
+            counts.insert(current, count);
+
+            if graph.is_target(&current) {
+                found.get_or_insert_with(|| (cost, vec![])).1.push(current);
+                continue;
+            }
+
+            for next in graph.adjacent(&current)? {
+                let next_cost = match get_cost(&current, &next) {
+                    None => continue,
+                    Some(new_cost) => cost + new_cost,
+                };
+
+                match cost_lookup.get(&next) {
+                    Some(&best) if next_cost > best => {}
+                    Some(&best) if next_cost == best => {
+                        parents.entry(next).or_default().push(current);
+                    }
+                    _ => {
+                        queue.push(Reverse((next_cost, next)));
+                        cost_lookup.insert(next, next_cost);
+                        parents.insert(next, vec![current]);
+                    }
+                }
+            }
+        }
+
+        let (cost, targets) = found?;
+
+        Some(ShortestPaths {
+            cost,
+            starts,
+            targets,
+            parents,
+            counts,
+        })
+    }
+
+    /// Cost from the closest start to every reachable node, skipping nodes beyond `max_cost`.
+    pub fn distances<G, N, C>(
+        graph: &G,
+        get_cost: impl Fn(&N, &N) -> Option<C>,
+        max_cost: Option<C>,
+    ) -> HashMap<N, C>
+    where
+        N: Copy + Hash + Eq + Ord,
+        G: super::Graph<N>,
+        C: Cost,
+    {
+        distances_from(graph, graph.starts(), get_cost, max_cost)
+    }
+
+    pub fn distances_from<G, N, C>(
+        graph: &G,
+        starts: impl IntoIterator<Item = N>,
+        get_cost: impl Fn(&N, &N) -> Option<C>,
+        max_cost: Option<C>,
+    ) -> HashMap<N, C>
+    where
+        N: Copy + Hash + Eq + Ord,
+        G: super::Graph<N>,
+        C: Cost,
+    {
+        let mut queue = BinaryHeap::new();
+        let mut cost_lookup = HashMap::with_capacity(graph.estimated_size());
+
+        for start in starts {
+            queue.push(Reverse((C::zero(), start)));
+            cost_lookup.insert(start, C::zero());
+        }
+
+        while let Some(Reverse((cost, current))) = queue.pop() {
+            if cost_lookup.get(&current).is_some_and(|&best| cost > best) {
+                continue;
+            }
+
+            for next in graph.adjacent(&current).into_iter().flatten() {
+                let next_cost = match get_cost(&current, &next) {
+                    None => continue,
+                    Some(new_cost) => cost + new_cost,
+                };
+
+                if max_cost.is_some_and(|max| next_cost > max) {
+                    continue;
+                }
+
+                if cost_lookup.get(&next).is_none_or(|&best| next_cost < best) {
+                    queue.push(Reverse((next_cost, next)));
+                    cost_lookup.insert(next, next_cost);
+                }
+            }
+        }
+
+        cost_lookup
+    }
+
+    /// Same as [`distances`] for graphs whose nodes are indices below `len`.
+    pub fn dense_distances<G, C>(
+        graph: &G,
+        len: usize,
+        get_cost: impl Fn(&usize, &usize) -> Option<C>,
+        max_cost: Option<C>,
+    ) -> Vec<Option<C>>
+    where
+        G: super::Graph<usize>,
+        C: Cost,
+    {
+        let mut queue = BinaryHeap::new();
+        let mut cost_lookup = vec![None; len];
+
+        for start in graph.starts() {
+            queue.push(Reverse((C::zero(), start)));
+            cost_lookup[start] = Some(C::zero());
+        }
+
+        while let Some(Reverse((cost, current))) = queue.pop() {
+            if cost_lookup[current].is_some_and(|best| cost > best) {
+                continue;
+            }
+
+            for next in graph.adjacent(&current).into_iter().flatten() {
+                let next_cost = match get_cost(&current, &next) {
+                    None => continue,
+                    Some(new_cost) => cost + new_cost,
+                };
+
+                if max_cost.is_some_and(|max| next_cost > max) {
+                    continue;
+                }
+
+                if cost_lookup[next].is_none_or(|best| next_cost < best) {
+                    queue.push(Reverse((next_cost, next)));
+                    cost_lookup[next] = Some(next_cost);
+                }
+            }
+        }
+
+        cost_lookup
+    }
 }
 
-fn get_path<N>(history: &HashMap<N, Option<N>>, start: &N, mut target: N) -> Option<Vec<N>>
-where
-    N: Copy + Hash + Eq,
-{
-    let mut path = vec![target];
+pub mod a_star {
+    use crate::pathfinding::{get_path, Cost};
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap};
+    use std::hash::Hash;
+
+    /// Same as [`super::dijkstra::solve`], with the queue ordered by `cost + heuristic(node)`.
+    ///
+    /// The heuristic must never overestimate the remaining cost to a target for the result to be
+    /// the shortest path.
+    pub fn solve<G, N, C>(
+        graph: &G,
+        get_cost: impl Fn(&N, &N) -> Option<C>,
+        heuristic: impl Fn(&N) -> C,
+    ) -> Option<(C, impl Fn() -> Option<Vec<N>>)>
+    where
+        N: Copy + Hash + Eq + Ord,
+        G: super::Graph<N>,
+        C: Cost,
+    {
+        let start = graph.start()?;
+        let mut queue = BinaryHeap::new();
+        let mut cost_lookup = HashMap::with_capacity(graph.estimated_size());
+        let mut history = HashMap::new();
+
+        queue.push(Reverse((heuristic(&start), C::zero(), start)));
+        cost_lookup.insert(start, C::zero());
+        history.insert(start, None);
+
+        while let Some(Reverse((_, cost, current))) = queue.pop() {
+            if graph.is_target(&current) {
+                return Some((cost, move || get_path(&history, current)));
+            }
+
+            if cost_lookup.get(&current).is_some_and(|&best| cost > best) {
+                continue;
+            }
+
+            for next in graph.adjacent(&current)? {
+                let next_cost = match get_cost(&current, &next) {
+                    None => continue,
+                    Some(new_cost) => cost + new_cost,
+                };
+
+                if cost_lookup.get(&next).is_none_or(|&best| next_cost < best) {
+                    queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+                    cost_lookup.insert(next, next_cost);
+                    history.insert(next, Some(current));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+pub mod bfs {
+    use std::collections::{HashMap, VecDeque};
+    use std::hash::Hash;
+
+    use super::{get_path, get_source, Graph, Reached};
+
+    pub fn solve<G, N>(graph: &G) -> Option<(N, impl Fn() -> Option<Vec<N>>)>
+    where
+        N: Copy + Hash + Eq,
+        G: Graph<N>,
+    {
+        let (reached, get_path) = solve_from(graph, graph.starts())?;
+
+        Some((reached.target, get_path))
+    }
+
+    /// Searches from every node in `starts` at once and stops at the closest target.
+    #[allow(clippy::type_complexity)]
+    pub fn solve_from<G, N>(
+        graph: &G,
+        starts: impl IntoIterator<Item = N>,
+    ) -> Option<(Reached<N, usize>, impl Fn() -> Option<Vec<N>>)>
+    where
+        N: Copy + Hash + Eq,
+        G: Graph<N>,
+    {
+        let mut history = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        for start in starts {
+            queue.push_back((0, start));
+            history.insert(start, None);
+        }
+
+        while let Some((steps, current)) = queue.pop_front() {
+            if graph.is_target(&current) {
+                let reached = Reached {
+                    source: get_source(&history, current),
+                    target: current,
+                    cost: steps,
+                };
+
+                return Some((reached, move || get_path(&history, current)));
+            }
+            for next in graph.adjacent(&current)? {
+                if history.contains_key(&next) {
+                    continue;
+                }
+
+                history.insert(next, Some(current));
+                queue.push_back((steps + 1, next));
+            }
+        }
+
+        None
+    }
+
+    /// Steps from the closest start to every reachable node, up to `max_depth` steps away.
+    pub fn distances<G, N>(graph: &G, max_depth: Option<usize>) -> HashMap<N, usize>
+    where
+        N: Copy + Hash + Eq,
+        G: Graph<N>,
+    {
+        distances_from(graph, graph.starts(), max_depth)
+    }
+
+    pub fn distances_from<G, N>(
+        graph: &G,
+        starts: impl IntoIterator<Item = N>,
+        max_depth: Option<usize>,
+    ) -> HashMap<N, usize>
+    where
+        N: Copy + Hash + Eq,
+        G: Graph<N>,
+    {
+        let mut distances = HashMap::with_capacity(graph.estimated_size());
+        let mut queue = VecDeque::new();
+
+        for start in starts {
+            queue.push_back((0, start));
+            distances.insert(start, 0);
+        }
+
+        while let Some((steps, current)) = queue.pop_front() {
+            if max_depth.is_some_and(|max| steps >= max) {
+                continue;
+            }
+
+            for next in graph.adjacent(&current).into_iter().flatten() {
+                if distances.contains_key(&next) {
+                    continue;
+                }
+
+                distances.insert(next, steps + 1);
+                queue.push_back((steps + 1, next));
+            }
+        }
+
+        distances
+    }
+
+    /// Same as [`distances`] for graphs whose nodes are indices below `len`.
+    pub fn dense_distances<G>(graph: &G, len: usize, max_depth: Option<usize>) -> Vec<Option<usize>>
+    where
+        G: Graph<usize>,
+    {
+        let mut distances = vec![None; len];
+        let mut queue = VecDeque::new();
+
+        for start in graph.starts() {
+            queue.push_back((0, start));
+            distances[start] = Some(0);
+        }
+
+        while let Some((steps, current)) = queue.pop_front() {
+            if max_depth.is_some_and(|max| steps >= max) {
+                continue;
+            }
 
-    while let Some(Some(parent)) = history.get(&target) {
-        path.push(*parent);
-        target = *parent;
+            for next in graph.adjacent(&current).into_iter().flatten() {
+                if distances[next].is_some() {
+                    continue;
+                }
 
-        if start == parent {
-            return Some(path.into_iter().rev().collect());
+                distances[next] = Some(steps + 1);
+                queue.push_back((steps + 1, next));
+            }
         }
+
+        distances
     }
-    None
 }
 
-pub mod dijkstra {
-    use crate::pathfinding::get_path;
-    use std::collections::{BinaryHeap, HashMap};
-    use std::fmt::Debug;
+/// Longest simple paths, for graphs where every node may be visited at most once.
+pub mod longest {
+    use std::collections::{HashMap, HashSet, VecDeque};
     use std::hash::Hash;
+    use std::ops::Sub;
 
-    pub fn solve<G, N>(
-        graph: &G,
-        get_cost: impl Fn(&N, &N) -> Option<i32>,
-    ) -> Option<(i32, impl Fn() -> Option<Vec<N>>)>
+    use super::{Compressed, Cost, Graph};
+
+    /// Every node a path can go through, paths ending at the first target they meet.
+    fn reachable<G, N>(graph: &G) -> Vec<N>
     where
-        N: Copy + Hash + Eq + Ord + Debug,
-        G: super::Graph<N>,
+        N: Copy + Hash + Eq,
+        G: Graph<N>,
     {
-        let start = graph.start()?;
-        let mut queue = BinaryHeap::new();
-        let mut cost_lookup = HashMap::with_capacity(graph.estimated_size());
-        let mut history = HashMap::new();
+        let mut visited: HashSet<_> = graph.starts().into_iter().collect();
+        let mut queue: VecDeque<_> = visited.iter().copied().collect();
+        let mut nodes = vec![];
 
-        queue.push((0, start));
-        cost_lookup.insert(start, 0);
-        history.insert(start, None);
+        while let Some(current) = queue.pop_front() {
+            nodes.push(current);
 
-        while let Some((cost, current)) = queue.pop() {
             if graph.is_target(&current) {
-                return Some((-cost, move || get_path(&history, &start, current)));
+                continue;
             }
 
-            if &cost > cost_lookup.get(&current).unwrap_or(&i32::MAX) {
-                continue;
+            for next in graph.adjacent(&current).into_iter().flatten() {
+                if visited.insert(next) {
+                    queue.push_back(next);
+                }
             }
+        }
 
-            for next in graph.adjacent(&current)? {
-                let next_cost = match get_cost(&current, &next) {
-                    None => continue,
-                    Some(new_cost) => -cost + new_cost,
+        nodes
+    }
+
+    /// Collapses every corridor (a node with at most two neighbors) into a single weighted edge
+    /// between the junctions, starts and targets it connects.
+    ///
+    /// Unlike [`compress`](super::compress), parallel corridors between two junctions are merged
+    /// into the longest one, so that the longest paths are kept.
+    pub fn contract<G, N, C>(graph: &G, get_cost: impl Fn(&N, &N) -> Option<C>) -> Compressed<N, C>
+    where
+        N: Copy + Hash + Eq + Ord,
+        G: Graph<N>,
+        C: Cost,
+    {
+        let is_junction = |node: &N| {
+            graph.is_target(node) || graph.adjacent(node).is_some_and(|next| next.len() > 2)
+        };
+        let starts = graph.starts();
+        let mut nodes = starts.clone();
+        let mut index: HashMap<_, _> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+        let mut edges = vec![];
+
+        while let Some(&from) = nodes.get(edges.len()) {
+            let mut found: Vec<(usize, C)> = vec![];
+
+            for first in graph.adjacent(&from).into_iter().flatten() {
+                let Some(mut cost) = get_cost(&from, &first) else {
+                    continue;
                 };
+                let (mut previous, mut current) = (from, first);
+                let mut seen = HashSet::from([from]);
 
-                if &next_cost < cost_lookup.get(&next).unwrap_or(&i32::MAX) {
-                    queue.push((-next_cost, next));
-                    cost_lookup.insert(next, next_cost);
-                    history.insert(next, Some(current));
+                // follow the corridor to its end, a fork of a directed graph ending it too
+                let end = loop {
+                    if index.contains_key(&current) || is_junction(&current) {
+                        break Some(current);
+                    }
+
+                    if !seen.insert(current) {
+                        break None;
+                    }
+
+                    let onward: Vec<N> = graph
+                        .adjacent(&current)
+                        .into_iter()
+                        .flatten()
+                        .filter(|next| *next != previous)
+                        .collect();
+
+                    match onward[..] {
+                        [] => break None,
+                        [next] => match get_cost(&current, &next) {
+                            Some(step) => {
+                                cost = cost + step;
+                                (previous, current) = (current, next);
+                            }
+                            None => break None,
+                        },
+                        _ => break Some(current),
+                    }
+                };
+
+                let Some(end) = end.filter(|end| *end != from) else {
+                    continue;
+                };
+                let to = *index.entry(end).or_insert_with(|| {
+                    nodes.push(end);
+                    nodes.len() - 1
+                });
+
+                match found.iter_mut().find(|(next, _)| *next == to) {
+                    Some((_, longest)) => *longest = (*longest).max(cost),
+                    None => found.push((to, cost)),
                 }
             }
+
+            edges.push(found);
         }
 
-        None
+        Compressed {
+            starts: (0..starts.len()).collect(),
+            targets: nodes.iter().map(|node| graph.is_target(node)).collect(),
+            nodes,
+            index,
+            edges,
+        }
     }
-}
 
-pub mod bfs {
-    use std::collections::{HashMap, VecDeque};
-    use std::hash::Hash;
+    struct Indexed<N, C> {
+        nodes: Vec<N>,
+        edges: Vec<Vec<(usize, C)>>,
+        targets: Vec<bool>,
+        starts: Vec<usize>,
+    }
+
+    /// Numbers every node reachable from the starts. Paths end at targets, so their edges are dropped.
+    fn index<G, N, C>(graph: &G, get_cost: impl Fn(&N, &N) -> Option<C>) -> Indexed<N, C>
+    where
+        N: Copy + Hash + Eq,
+        G: Graph<N>,
+    {
+        let nodes = reachable(graph);
+        let lookup: HashMap<_, _> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+        let targets: Vec<_> = nodes.iter().map(|node| graph.is_target(node)).collect();
 
-    use super::{get_path, Graph};
+        let edges = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| match targets[i] {
+                true => vec![],
+                false => graph
+                    .adjacent(node)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|next| Some((*lookup.get(&next)?, get_cost(node, &next)?)))
+                    .collect(),
+            })
+            .collect();
 
-    pub fn solve<G, N>(graph: &G) -> Option<(N, impl Fn() -> Option<Vec<N>>)>
+        Indexed {
+            starts: graph
+                .starts()
+                .iter()
+                .filter_map(|n| lookup.get(n).copied())
+                .collect(),
+            nodes,
+            edges,
+            targets,
+        }
+    }
+
+    /// Longest path from any start to any target of a directed acyclic graph, in linear time.
+    ///
+    /// Returns `None` if no target is reachable or if the reachable part of the graph has a cycle.
+    pub fn dag<G, N, C>(graph: &G, get_cost: impl Fn(&N, &N) -> Option<C>) -> Option<C>
     where
         N: Copy + Hash + Eq,
         G: Graph<N>,
+        C: Cost,
     {
-        let mut history = HashMap::new();
-        let mut queue = VecDeque::new();
+        let Indexed {
+            nodes,
+            edges,
+            targets,
+            starts,
+        } = index(graph, get_cost);
 
-        let start = graph.start()?;
+        let mut in_degree = vec![0; nodes.len()];
+        for (next, _) in edges.iter().flatten() {
+            in_degree[*next] += 1;
+        }
 
-        queue.push_back(start);
-        history.insert(start, None);
+        let mut longest = vec![None; nodes.len()];
+        for start in starts {
+            longest[start] = Some(C::zero());
+        }
+
+        let mut queue: VecDeque<_> = (0..nodes.len()).filter(|&i| in_degree[i] == 0).collect();
+        let mut sorted = 0;
 
         while let Some(current) = queue.pop_front() {
-            if graph.is_target(&current) {
-                return Some((current, move || get_path(&history, &start, current)));
+            sorted += 1;
+
+            for &(next, cost) in &edges[current] {
+                if let Some(cost) = longest[current].map(|current: C| current + cost) {
+                    longest[next] = Some(longest[next].map_or(cost, |best: C| best.max(cost)));
+                }
+
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    queue.push_back(next);
+                }
             }
-            for next in graph.adjacent(&current)? {
-                if history.contains_key(&next) {
+        }
+
+        if sorted < nodes.len() {
+            return None;
+        }
+
+        (0..nodes.len())
+            .filter(|&i| targets[i])
+            .filter_map(|i| longest[i])
+            .max()
+    }
+
+    struct Search<'a, C> {
+        edges: &'a [Vec<(usize, C)>],
+        targets: &'a [bool],
+        max_in: &'a [C],
+        forced: &'a [Option<usize>],
+        best: Option<C>,
+    }
+
+    impl<C> Search<'_, C>
+    where
+        C: Cost + Sub<Output = C>,
+    {
+        fn dfs(&mut self, current: usize, visited: u128, cost: C, remaining: C) {
+            if self.targets[current] {
+                self.best = Some(self.best.map_or(cost, |best| best.max(cost)));
+                return;
+            }
+
+            if self.best.is_some_and(|best| cost + remaining <= best) {
+                return;
+            }
+
+            let edges = self.edges;
+
+            for &(next, next_cost) in &edges[current] {
+                if visited & (1 << next) != 0 {
+                    continue;
+                }
+                if self.forced[current].is_some_and(|target| target != next) {
                     continue;
                 }
 
-                history.insert(next, Some(current));
-                queue.push_back(next);
+                self.dfs(
+                    next,
+                    visited | (1 << next),
+                    cost + next_cost,
+                    remaining - self.max_in[next],
+                );
             }
         }
+    }
 
-        None
+    /// Longest simple path from any start to any target of a general graph.
+    ///
+    /// Explores every simple path with a bitmask of visited nodes, cutting branches that cannot
+    /// beat the best path found so far. Meant for small graphs, see [`contract`].
+    ///
+    /// # Panics
+    /// If more than 128 nodes are reachable from the starts.
+    pub fn simple<G, N, C>(graph: &G, get_cost: impl Fn(&N, &N) -> Option<C>) -> Option<C>
+    where
+        N: Copy + Hash + Eq,
+        G: Graph<N>,
+        C: Cost + Sub<Output = C>,
+    {
+        let Indexed {
+            nodes,
+            edges,
+            targets,
+            starts,
+        } = index(graph, get_cost);
+
+        assert!(
+            nodes.len() <= 128,
+            "{} nodes do not fit in the visited mask, contract the graph first",
+            nodes.len()
+        );
+
+        // upper bound on what entering a node can add to a path
+        let mut max_in = vec![C::zero(); nodes.len()];
+        let mut predecessors = vec![HashSet::new(); nodes.len()];
+        for (from, next, cost) in edges
+            .iter()
+            .enumerate()
+            .flat_map(|(from, edges)| edges.iter().map(move |(next, cost)| (from, *next, *cost)))
+        {
+            max_in[next] = max_in[next].max(cost);
+            predecessors[next].insert(from);
+        }
+
+        // with a single target behind a single node, leaving that node any other way is a dead end
+        let mut forced = vec![None; nodes.len()];
+        if let [target] = (0..nodes.len()).filter(|&i| targets[i]).collect::<Vec<_>>()[..] {
+            if let [&last] = predecessors[target].iter().collect::<Vec<_>>()[..] {
+                forced[last] = Some(target);
+            }
+        }
+
+        let total = max_in.iter().fold(C::zero(), |total, &cost| total + cost);
+        let mut search = Search {
+            edges: &edges,
+            targets: &targets,
+            max_in: &max_in,
+            forced: &forced,
+            best: None,
+        };
+
+        for start in starts {
+            search.dfs(start, 1 << start, C::zero(), total - max_in[start]);
+        }
+
+        search.best
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::pathfinding::*;
+    use itertools::Itertools;
 
     struct Alphabet;
 
@@ -148,4 +1140,347 @@ mod tests {
         assert_eq!(25, cost);
         assert_eq!(26, get_path().expect("path not found").len());
     }
+
+    #[test]
+    fn a_star_simple() {
+        let (cost, get_path) =
+            a_star::solve(&Alphabet, |_, _| Some(1), |node| 'Z' as i32 - *node as i32)
+                .expect("path not found");
+        let (expected, _) = dijkstra::solve(&Alphabet, |_, _| Some(1)).expect("path not found");
+
+        assert_eq!(expected, cost);
+        assert_eq!(26, get_path().expect("path not found").len());
+    }
+
+    struct Cavern(Vec<Vec<i32>>);
+
+    impl Cavern {
+        fn target(&self) -> (usize, usize) {
+            (self.0[0].len() - 1, self.0.len() - 1)
+        }
+
+        fn cost(&self, _: &(usize, usize), &(x, y): &(usize, usize)) -> Option<i32> {
+            self.0.get(y)?.get(x).copied()
+        }
+    }
+
+    impl Graph<(usize, usize)> for Cavern {
+        fn start(&self) -> Option<(usize, usize)> {
+            Some((0, 0))
+        }
+        fn adjacent(&self, &(x, y): &(usize, usize)) -> Option<Vec<(usize, usize)>> {
+            Some(crate::neighbors(self.0[0].len(), self.0.len(), x, y))
+        }
+        fn is_target(&self, node: &(usize, usize)) -> bool {
+            node == &self.target()
+        }
+    }
+
+    #[test]
+    fn a_star_weighted_grid() {
+        let cavern = Cavern(
+            "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n1319128137\n1359912421\n3125421639\n1293138521\n2311944581"
+                .lines()
+                .map(|line| line.bytes().map(|c| (c - b'0') as i32).collect())
+                .collect(),
+        );
+        let (tx, ty) = cavern.target();
+
+        let (cost, get_path) = a_star::solve(
+            &cavern,
+            |a, b| cavern.cost(a, b),
+            |&(x, y)| (tx.abs_diff(x) + ty.abs_diff(y)) as i32,
+        )
+        .expect("path not found");
+        let (expected, _) =
+            dijkstra::solve(&cavern, |a, b| cavern.cost(a, b)).expect("path not found");
+        let path = get_path().expect("path not found");
+
+        assert_eq!(40, cost);
+        assert_eq!(expected, cost);
+        assert_eq!(
+            cost,
+            path.iter()
+                .skip(1)
+                .map(|&(x, y)| cavern.0[y][x])
+                .sum::<i32>()
+        );
+    }
+
+    #[test]
+    fn dijkstra_large_cost() {
+        let weight = 1u64 << 32;
+        let (cost, _) = dijkstra::solve(&Alphabet, |_, _| Some(weight)).expect("path not found");
+        let (heuristic_cost, _) = a_star::solve(
+            &Alphabet,
+            |_, _| Some(weight),
+            |node| ('Z' as u64 - *node as u64) * weight,
+        )
+        .expect("path not found");
+
+        assert!(cost > i32::MAX as u64);
+        assert_eq!(25 * weight, cost);
+        assert_eq!(cost, heuristic_cost);
+    }
+
+    #[test]
+    fn dijkstra_fractional_cost() {
+        let (cost, _) =
+            dijkstra::solve(&Alphabet, |_, _| Some(OrdF64(0.5))).expect("path not found");
+
+        assert_eq!(OrdF64(12.5), cost);
+    }
+
+    #[test]
+    fn dijkstra_all_shortest_paths() {
+        let cavern = Cavern(vec![vec![1; 3]; 3]);
+        let paths = dijkstra::solve_all(&cavern, |a, b| cavern.cost(a, b)).expect("path not found");
+
+        assert_eq!(4, paths.cost());
+        assert_eq!(6, paths.count_shortest_paths());
+        assert_eq!(9, paths.nodes_on_any_shortest_path().len());
+        assert_eq!(6, paths.paths().unique().count());
+        assert!(paths
+            .paths()
+            .all(|path| path.len() == 5 && path[0] == (0, 0) && path[4] == (2, 2)));
+    }
+
+    #[test]
+    fn dijkstra_all_shortest_paths_weighted() {
+        let cavern = Cavern(vec![vec![1, 1, 1], vec![1, 9, 1], vec![1, 1, 1]]);
+        let paths = dijkstra::solve_all(&cavern, |a, b| cavern.cost(a, b)).expect("path not found");
+
+        assert_eq!(4, paths.cost());
+        assert_eq!(2, paths.count_shortest_paths());
+        assert!(!paths.nodes_on_any_shortest_path().contains(&(1, 1)));
+        assert_eq!(8, paths.nodes_on_any_shortest_path().len());
+        assert_eq!(
+            vec![
+                vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
+                vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            ],
+            paths.paths().sorted().collect_vec()
+        );
+    }
+
+    struct Sources(Cavern, Vec<(usize, usize)>);
+
+    impl Graph<(usize, usize)> for Sources {
+        fn start(&self) -> Option<(usize, usize)> {
+            self.1.first().copied()
+        }
+        fn adjacent(&self, node: &(usize, usize)) -> Option<Vec<(usize, usize)>> {
+            self.0.adjacent(node)
+        }
+        fn is_target(&self, node: &(usize, usize)) -> bool {
+            self.0.is_target(node)
+        }
+        fn starts(&self) -> Vec<(usize, usize)> {
+            self.1.clone()
+        }
+    }
+
+    #[test]
+    fn dijkstra_all_shortest_paths_multi_source() {
+        let sources = Sources(Cavern(vec![vec![1; 3]; 3]), vec![(0, 0), (2, 0), (0, 2)]);
+        let paths =
+            dijkstra::solve_all(&sources, |a, b| sources.0.cost(a, b)).expect("path not found");
+
+        assert_eq!(2, paths.cost());
+        assert_eq!(2, paths.count_shortest_paths());
+        assert_eq!(
+            vec![vec![(0, 2), (1, 2), (2, 2)], vec![(2, 0), (2, 1), (2, 2)],],
+            paths.paths().sorted().collect_vec()
+        );
+    }
+
+    #[test]
+    fn bfs_multi_source() {
+        let (reached, get_path) =
+            bfs::solve_from(&Alphabet, ['B', 'M', 'W']).expect("path not found");
+
+        assert_eq!(
+            Reached {
+                source: 'W',
+                target: 'Z',
+                cost: 3
+            },
+            reached
+        );
+        assert_eq!(
+            vec!['W', 'X', 'Y', 'Z'],
+            get_path().expect("path not found")
+        );
+    }
+
+    #[test]
+    fn dijkstra_multi_source() {
+        let cavern = Cavern(vec![vec![1, 1, 1], vec![1, 1, 1], vec![1, 9, 1]]);
+        let (reached, get_path) =
+            dijkstra::solve_from(&cavern, [(0, 2), (2, 0)], |a, b| cavern.cost(a, b))
+                .expect("path not found");
+
+        assert_eq!((2, 0), reached.source);
+        assert_eq!(2, reached.cost);
+        assert_eq!(
+            vec![(2, 0), (2, 1), (2, 2)],
+            get_path().expect("path not found")
+        );
+    }
+
+    #[test]
+    fn search_from_target() {
+        let (reached, get_path) = bfs::solve_from(&Alphabet, ['Z']).expect("path not found");
+
+        assert_eq!(0, reached.cost);
+        assert_eq!(vec!['Z'], get_path().expect("path not found"));
+    }
+
+    #[test]
+    fn bfs_distances() {
+        let distances = bfs::distances(&Alphabet, Some(3));
+
+        assert_eq!(4, distances.len());
+        assert_eq!(Some(&3), distances.get(&'D'));
+        assert_eq!(26, bfs::distances(&Alphabet, Some(25)).len());
+    }
+
+    #[test]
+    fn dijkstra_distances() {
+        let cavern = Cavern(vec![vec![1, 1, 1], vec![1, 9, 1], vec![1, 1, 1]]);
+        let distances = dijkstra::distances(&cavern, |a, b| cavern.cost(a, b), None);
+
+        assert_eq!(9, distances.len());
+        assert_eq!(Some(&4), distances.get(&(2, 2)));
+        assert_eq!(Some(&10), distances.get(&(1, 1)));
+
+        let distances = dijkstra::distances(&cavern, |a, b| cavern.cost(a, b), Some(3));
+
+        assert_eq!(7, distances.len());
+        assert!(!distances.contains_key(&(1, 1)));
+    }
+
+    struct Ring(usize);
+
+    impl Graph<usize> for Ring {
+        fn start(&self) -> Option<usize> {
+            Some(0)
+        }
+        fn adjacent(&self, node: &usize) -> Option<Vec<usize>> {
+            Some(vec![(node + 1) % self.0, (node + self.0 - 1) % self.0])
+        }
+        fn is_target(&self, _: &usize) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn dense_distances() {
+        let ring = Ring(6);
+
+        assert_eq!(
+            vec![Some(0), Some(1), Some(2), Some(3), Some(2), Some(1)],
+            bfs::dense_distances(&ring, 6, None)
+        );
+        assert_eq!(
+            vec![Some(0), Some(1), None, None, None, Some(1)],
+            bfs::dense_distances(&ring, 6, Some(1))
+        );
+        assert_eq!(
+            vec![Some(0), Some(2), Some(4), Some(6), Some(4), Some(2)],
+            dijkstra::dense_distances(&ring, 6, |_, _| Some(2), None)
+        );
+    }
+
+    #[test]
+    fn compress_grid() {
+        let cavern = Cavern(vec![vec![1, 1, 1], vec![1, 9, 1], vec![1, 1, 1]]);
+        let compressed = compress(
+            &cavern,
+            |a, b| cavern.cost(a, b),
+            |&(x, y)| x != 1 && y != 1,
+        );
+        let target = compressed.index_of(&(2, 2)).expect("corner is kept");
+
+        assert_eq!(4, compressed.len());
+        assert_eq!(Some((0, 0)), compressed.node(0));
+        assert_eq!(Some(12), compressed.cost(0, target));
+        assert_eq!(Some(4), compressed.distance_matrix()[0][target]);
+        assert_eq!(
+            Some(4),
+            dijkstra::solve(&compressed, |a, b| compressed.cost(*a, *b)).map(|(cost, _)| cost)
+        );
+    }
+
+    #[test]
+    fn longest_dag() {
+        assert_eq!(Some(25), longest::dag(&Alphabet, |_, _| Some(1)));
+
+        let cavern = Cavern(vec![vec![1; 3]; 3]);
+        assert_eq!(None, longest::dag(&cavern, |a, b| cavern.cost(a, b)));
+    }
+
+    #[test]
+    fn longest_simple() {
+        let cavern = Cavern(vec![vec![1, 1, 1], vec![1, 9, 1], vec![1, 1, 1]]);
+
+        assert_eq!(Some(16), longest::simple(&cavern, |a, b| cavern.cost(a, b)));
+        assert_eq!(Some(25), longest::simple(&Alphabet, |_, _| Some(1)));
+    }
+
+    #[test]
+    fn longest_contracted() {
+        let cavern = Cavern(vec![vec![1, 1, 1], vec![1, 9, 1], vec![1, 1, 1]]);
+        let contracted = longest::contract(&cavern, |a, b| cavern.cost(a, b));
+
+        assert_eq!(7, contracted.len());
+        assert_eq!(None, contracted.index_of(&(2, 0)));
+        assert_eq!(
+            Some(2),
+            contracted.cost(
+                contracted.index_of(&(1, 0)).unwrap(),
+                contracted.index_of(&(2, 1)).unwrap()
+            )
+        );
+        assert_eq!(
+            Some(16),
+            longest::simple(&contracted, |a, b| contracted.cost(*a, *b))
+        );
+    }
+
+    /// Undirected graph from 0 to 9, as adjacency lists.
+    struct Corridors(Vec<Vec<usize>>);
+
+    impl Graph<usize> for Corridors {
+        fn start(&self) -> Option<usize> {
+            Some(0)
+        }
+        fn adjacent(&self, node: &usize) -> Option<Vec<usize>> {
+            self.0.get(*node).cloned()
+        }
+        fn is_target(&self, node: &usize) -> bool {
+            *node == 9
+        }
+    }
+
+    #[test]
+    fn longest_contracted_parallel_corridors() {
+        // 0-1-9 and 0-2-3-4-9
+        let mut adjacency = vec![vec![]; 10];
+        for (a, b) in [(0, 1), (1, 9), (0, 2), (2, 3), (3, 4), (4, 9)] {
+            adjacency[a].push(b);
+            adjacency[b].push(a);
+        }
+        let corridors = Corridors(adjacency);
+        let contracted = longest::contract(&corridors, |_, _| Some(1));
+        let target = contracted.index_of(&9).expect("target is kept");
+
+        assert_eq!(Some(4), longest::simple(&corridors, |_, _| Some(1)));
+        assert_eq!(2, contracted.len());
+        assert_eq!(Some(4), contracted.cost(0, target));
+        assert_eq!(
+            Some(4),
+            longest::simple(&contracted, |a, b| contracted.cost(*a, *b))
+        );
+    }
 }
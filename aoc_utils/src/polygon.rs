@@ -0,0 +1,231 @@
+//! Simple polygons with integer vertices: shoelace area, Pick's theorem and point location.
+
+use crate::number::{gcd, Signed};
+
+/// Where a point is relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Closed polygon through its vertices, in order, the last one joined back to the first.
+///
+/// ```
+/// use aoc_utils::polygon::{Location, Polygon};
+///
+/// // 4x3 rectangle, counter-clockwise with y going up
+/// let polygon = Polygon::from_moves((0i64, 0), [(4, 0), (0, 3), (-4, 0), (0, -3)]);
+///
+/// assert_eq!(24, polygon.twice_signed_area());
+/// assert_eq!(14, polygon.perimeter());
+/// assert_eq!((14, 6), (polygon.boundary_points(), polygon.interior_points()));
+/// assert_eq!(Location::Inside, polygon.locate((1, 2)));
+/// assert_eq!(Location::Boundary, polygon.locate((4, 1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon<T> {
+    vertices: Vec<(T, T)>,
+}
+
+fn abs<T: Signed>(x: T) -> T {
+    match x < T::ZERO {
+        true => -x,
+        false => x,
+    }
+}
+
+impl<T: Signed> Polygon<T> {
+    pub fn new(vertices: Vec<(T, T)>) -> Self {
+        Self { vertices }
+    }
+
+    /// The polygon walked from `start`, each move being added to the previous vertex. A last move
+    /// back to `start` is optional.
+    pub fn from_moves(start: (T, T), moves: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut vertices = vec![start];
+
+        for (dx, dy) in moves {
+            let (x, y) = vertices[vertices.len() - 1];
+
+            vertices.push((x + dx, y + dy));
+        }
+
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[(T, T)] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = ((T, T), (T, T))> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the area, with the shoelace formula to stay exact: positive when the vertices turn
+    /// counter-clockwise with y going up, so clockwise with y going down as in a grid.
+    pub fn twice_signed_area(&self) -> T {
+        self.edges().fold(T::ZERO, |area, ((x0, y0), (x1, y1))| {
+            area + x0 * y1 - x1 * y0
+        })
+    }
+
+    /// Length of the boundary, as the Manhattan length of the edges: exact when they are
+    /// horizontal or vertical.
+    pub fn perimeter(&self) -> T {
+        self.edges().fold(T::ZERO, |length, ((x0, y0), (x1, y1))| {
+            length + abs(x1 - x0) + abs(y1 - y0)
+        })
+    }
+
+    /// Number of integer points on the boundary.
+    pub fn boundary_points(&self) -> T {
+        self.edges().fold(T::ZERO, |count, ((x0, y0), (x1, y1))| {
+            count + gcd(x1 - x0, y1 - y0)
+        })
+    }
+
+    /// Number of integer points strictly inside, with Pick's theorem: `A = i + b / 2 - 1`.
+    pub fn interior_points(&self) -> T {
+        let two = T::ONE + T::ONE;
+
+        (abs(self.twice_signed_area()) - self.boundary_points() + two) / two
+    }
+
+    /// Whether the point is inside, on the boundary or outside, casting a ray towards the right.
+    pub fn locate(&self, (x, y): (T, T)) -> Location {
+        let mut inside = false;
+
+        for ((x0, y0), (x1, y1)) in self.edges() {
+            let cross = (x1 - x0) * (y - y0) - (x - x0) * (y1 - y0);
+
+            if cross == T::ZERO
+                && x0.min(x1) <= x
+                && x <= x0.max(x1)
+                && y0.min(y1) <= y
+                && y <= y0.max(y1)
+            {
+                return Location::Boundary;
+            }
+
+            // edges are half-open in y, so a ray through a vertex crosses once
+            if (y0 > y) != (y1 > y) && (cross > T::ZERO) == (y1 > y0) {
+                inside = !inside;
+            }
+        }
+
+        match inside {
+            true => Location::Inside,
+            false => Location::Outside,
+        }
+    }
+}
+
+impl<T: Signed> FromIterator<(T, T)> for Polygon<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polygon::*;
+
+    /// The outline of the cells of a grid, `#` marking those in the polygon, checked by counting.
+    const SHAPES: [&str; 5] = [
+        "#",
+        "###\n#..\n###",
+        "##...\n##...\n#####\n....#",
+        ".#.\n###\n.#.",
+        "#...\n##..\n.##.\n..##",
+    ];
+
+    /// Corners of the grid points on the border of the cells, walked around their outline.
+    fn outline(shape: &str) -> Polygon<i64> {
+        let cells: Vec<(i64, i64)> = shape
+            .lines()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.char_indices()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| (x as i64, y as i64))
+            })
+            .collect();
+        let is_cell = |x, y| cells.contains(&(x, y));
+        let start = cells[0];
+        let (mut position, mut dir) = (start, (1, 0));
+        let mut vertices = vec![];
+
+        // keep the cells on the right hand side, from the top-left corner of the first one
+        loop {
+            vertices.push(position);
+
+            let (x, y) = position;
+            let (ahead_left, ahead_right) = match dir {
+                (1, 0) => (is_cell(x, y - 1), is_cell(x, y)),
+                (0, 1) => (is_cell(x, y), is_cell(x - 1, y)),
+                (-1, 0) => (is_cell(x - 1, y), is_cell(x - 1, y - 1)),
+                _ => (is_cell(x - 1, y - 1), is_cell(x, y - 1)),
+            };
+
+            dir = match (ahead_left, ahead_right) {
+                (true, _) => (dir.1, -dir.0),
+                (false, true) => dir,
+                (false, false) => (-dir.1, dir.0),
+            };
+            position = (position.0 + dir.0, position.1 + dir.1);
+
+            if position == start {
+                break Polygon::new(vertices);
+            }
+        }
+    }
+
+    #[test]
+    fn grid_outlines() {
+        for shape in SHAPES {
+            let polygon = &outline(shape);
+            let cells = shape.chars().filter(|c| *c == '#').count() as i64;
+            let points: Vec<_> = (-1..=6)
+                .flat_map(|y| (-1..=6).map(move |x| polygon.locate((x, y))))
+                .collect();
+            let count = |location| points.iter().filter(|l| **l == location).count() as i64;
+
+            assert_eq!(2 * cells, polygon.twice_signed_area(), "{shape}");
+            assert_eq!(polygon.perimeter(), polygon.boundary_points(), "{shape}");
+            assert_eq!(count(Location::Boundary), polygon.boundary_points());
+            assert_eq!(count(Location::Inside), polygon.interior_points());
+        }
+    }
+
+    #[test]
+    fn lattice_polygons() {
+        // triangle with slanted edges, and the same shape reversed
+        let triangle: Polygon<i128> = [(0, 0), (6, 0), (0, 4)].into_iter().collect();
+        let reversed: Polygon<i128> = [(0, 4), (6, 0), (0, 0)].into_iter().collect();
+
+        assert_eq!(24, triangle.twice_signed_area());
+        assert_eq!(-24, reversed.twice_signed_area());
+        assert_eq!(12, triangle.boundary_points());
+        assert_eq!(7, triangle.interior_points());
+        assert_eq!(7, reversed.interior_points());
+        assert_eq!(Location::Boundary, triangle.locate((3, 2)));
+        assert_eq!(Location::Inside, reversed.locate((2, 1)));
+        assert_eq!(Location::Outside, triangle.locate((4, 2)));
+        assert_eq!(Location::Outside, triangle.locate((-1, 0)));
+
+        let huge = Polygon::from_moves((0i128, 0), [(1 << 40, 0), (0, 1 << 40), (-(1 << 40), 0)]);
+
+        assert_eq!(1 << 81, huge.twice_signed_area());
+        assert_eq!(((1 << 40) - 1) * ((1 << 40) - 1), huge.interior_points());
+        assert_eq!(4, huge.vertices().len());
+    }
+}
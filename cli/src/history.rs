@@ -0,0 +1,224 @@
+//! Benchmarks saved across commits, to follow the performance of the days over time.
+//!
+//! `aoc bench --save` appends its results to a CSV history, keyed by the git commit and the
+//! machine they were measured on; `aoc report` compares the medians of the commits of a machine.
+
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc::bench::{Benchmark, Stats, COLUMNS};
+use aoc::solution::SolutionError;
+
+/// History used when none is given, in the working directory.
+pub const HISTORY: &str = "bench-history.csv";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub commit: String,
+    pub machine: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub step: String,
+    pub stats: Stats,
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// Short hash of the checked out commit, marked `-dirty` when tracked files were changed.
+pub fn commit() -> String {
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) if dirty => format!("{hash}-dirty"),
+        Some(hash) => hash,
+        None => "unknown".to_owned(),
+    }
+}
+
+/// Host name of this machine.
+pub fn machine() -> String {
+    ["HOSTNAME", "COMPUTERNAME"]
+        .into_iter()
+        .find_map(|name| std::env::var(name).ok())
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| {
+            let output = Command::new("hostname").output().ok()?;
+
+            Some(String::from_utf8_lossy(&output.stdout).into_owned())
+        })
+        .map(|name| name.trim().replace(',', "_"))
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+/// Appends the benchmarks to the history at `path`, created with a header if needed.
+pub fn append(
+    path: &str,
+    commit: &str,
+    machine: &str,
+    benchmarks: &[Benchmark],
+) -> std::io::Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut lines = String::new();
+
+    if file.metadata()?.len() == 0 {
+        lines += &format!("commit,machine,timestamp,{}\n", COLUMNS.join(","));
+    }
+
+    for benchmark in benchmarks {
+        lines += &format!("{commit},{machine},{timestamp},{}\n", benchmark.csv_row());
+    }
+
+    file.write_all(lines.as_bytes())
+}
+
+fn entry(line: &str) -> Option<Entry> {
+    let [commit, machine, timestamp, year, day, step, runs, min, median, p95, allocations] =
+        line.split(',').collect::<Vec<_>>()[..]
+    else {
+        return None;
+    };
+    let nanos = |ns: &str| ns.parse().ok().map(Duration::from_nanos);
+
+    Some(Entry {
+        commit: commit.to_owned(),
+        machine: machine.to_owned(),
+        timestamp: timestamp.parse().ok()?,
+        year: year.parse().ok()?,
+        day: day.parse().ok()?,
+        step: step.to_owned(),
+        stats: Stats {
+            runs: runs.parse().ok()?,
+            min: nanos(min)?,
+            median: nanos(median)?,
+            p95: nanos(p95)?,
+            allocations: match allocations {
+                "" => None,
+                count => Some(count.parse().ok()?),
+            },
+        },
+    })
+}
+
+/// Parses a history, skipping its header.
+pub fn parse(history: &str) -> Result<Vec<Entry>, SolutionError> {
+    history
+        .lines()
+        .skip(1)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            entry(line).ok_or_else(|| SolutionError::expected(history, line, "a benchmark entry"))
+        })
+        .collect()
+}
+
+/// Medians of a step of a day over the commits it was benchmarked at, oldest first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trend {
+    pub year: u16,
+    pub day: u8,
+    pub step: String,
+    pub medians: Vec<(String, Duration)>,
+}
+
+impl Trend {
+    /// Change of the last median from the previous one, in percent.
+    pub fn change(&self) -> Option<f64> {
+        let [.., (_, previous), (_, last)] = self.medians[..] else {
+            return None;
+        };
+
+        (!previous.is_zero()).then(|| (last.as_secs_f64() / previous.as_secs_f64() - 1.) * 100.)
+    }
+}
+
+/// The trends of the entries measured on `machine`, by day and step, in the order of the history.
+/// A commit benchmarked again moves to the end, with its latest median.
+pub fn trends(entries: &[Entry], machine: &str) -> Vec<Trend> {
+    let mut trends: BTreeMap<_, Vec<(String, Duration)>> = BTreeMap::new();
+
+    for entry in entries.iter().filter(|entry| entry.machine == machine) {
+        let medians = trends
+            .entry((entry.year, entry.day, entry.step.as_str()))
+            .or_default();
+
+        medians.retain(|(commit, _)| commit != &entry.commit);
+        medians.push((entry.commit.clone(), entry.stats.median));
+    }
+
+    trends
+        .into_iter()
+        .map(|((year, day, step), medians)| Trend {
+            year,
+            day,
+            step: step.to_owned(),
+            medians,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORY: &str = "\
+commit,machine,timestamp,year,day,step,runs,min_ns,median_ns,p95_ns,allocations
+a1,box,100,2021,1,parse,10,900,1000,1100,3
+a1,box,100,2021,1,part1,10,90,100,110,
+a1,laptop,100,2021,1,parse,10,1,2,3,
+b2,box,200,2021,1,parse,10,1100,1250,1300,3
+c3,box,300,2021,1,part1,10,80,95,100,
+a1,box,400,2021,1,part1,10,80,90,100,
+";
+
+    #[test]
+    fn parse_history() {
+        let entries = parse(HISTORY).expect("valid history");
+
+        assert_eq!(6, entries.len());
+        assert_eq!(Some(3), entries[0].stats.allocations);
+        assert_eq!(None, entries[1].stats.allocations);
+        assert_eq!(Duration::from_nanos(1250), entries[3].stats.median);
+
+        let Err(SolutionError::InvalidInput(err)) = parse("header\na1,box,1,2021") else {
+            panic!("expected an invalid entry")
+        };
+        assert_eq!(2, err.line);
+    }
+
+    #[test]
+    fn machine_trends() {
+        let entries = parse(HISTORY).expect("valid history");
+        let trends = trends(&entries, "box");
+        fn medians(trend: &Trend) -> Vec<(&str, u128)> {
+            trend
+                .medians
+                .iter()
+                .map(|(commit, median)| (commit.as_str(), median.as_nanos()))
+                .collect()
+        }
+
+        assert_eq!(2, trends.len());
+        assert_eq!(vec![("a1", 1000), ("b2", 1250)], medians(&trends[0]));
+        assert!(trends[0].change().is_some_and(|change| (change - 25.).abs() < 1e-9));
+        assert_eq!(vec![("c3", 95), ("a1", 90)], medians(&trends[1]));
+        assert!(trends[1].change().is_some_and(|change| change < 0.));
+        assert_eq!(None, self::trends(&entries, "laptop")[0].change());
+    }
+}
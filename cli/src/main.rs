@@ -0,0 +1,978 @@
+mod history;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc::answers::Answers;
+use aoc::bench::{Benchmark, Counting, Options, Runs};
+use aoc::registry::Solver;
+use aoc::solution::{read_source, Answer, Part, Report, SolutionError};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+const USAGE: &str = "\
+usage:
+    aoc list
+    aoc run <year> <day> [--part <1|2>] [--input <file>, - for stdin] [--record]
+    aoc run <year> --all [--part <1|2>] [--record]
+    aoc run --verify [<year>]
+    aoc bench <year> <day>|--all [--part <1|2>] [--warmup <runs>] [--runs <runs>|--time <ms>]
+        [--json|--csv] [--output <file>] [--save] [--history <file>]
+    aoc report [<year>] [--threshold <percent>] [--machine <name>] [--history <file>]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    List,
+    Run {
+        year: u16,
+        day: Option<u8>,
+        parts: Vec<Part>,
+        input: Option<String>,
+        record: bool,
+    },
+    Verify {
+        year: Option<u16>,
+    },
+    Bench {
+        year: u16,
+        day: Option<u8>,
+        parts: Vec<Part>,
+        options: Options,
+        format: Format,
+        output: Option<String>,
+        /// History to append the results to, see [`history`].
+        history: Option<String>,
+    },
+    Report {
+        year: Option<u16>,
+        history: String,
+        /// Change of the median, in percent, above which a day regressed.
+        threshold: usize,
+        machine: Option<String>,
+    },
+}
+
+/// Reads the positional arguments: the year, then the day.
+fn year_or_day(arg: &str, year: &mut Option<u16>, day: &mut Option<u8>) -> Result<(), String> {
+    match (&year, &day) {
+        (None, _) => *year = Some(arg.parse().map_err(|_| format!("invalid year '{arg}'"))?),
+        (_, None) => *day = Some(arg.parse().map_err(|_| format!("invalid day '{arg}'"))?),
+        _ => return Err(format!("unexpected argument '{arg}'")),
+    }
+
+    Ok(())
+}
+
+/// The year, and a single day unless `--all` was given.
+fn selection(year: Option<u16>, day: Option<u8>, all: bool) -> Result<(u16, Option<u8>), String> {
+    let year = year.ok_or("missing year")?;
+
+    match (day, all) {
+        (None, false) => Err("missing day, or --all".to_owned()),
+        (Some(_), true) => Err("--all runs every day, drop the day".to_owned()),
+        _ => Ok((year, day)),
+    }
+}
+
+fn only_part(arg: Option<String>) -> Result<Vec<Part>, String> {
+    match arg.as_deref() {
+        Some("1") => Ok(vec![Part::One]),
+        Some("2") => Ok(vec![Part::Two]),
+        _ => Err("--part expects 1 or 2".to_owned()),
+    }
+}
+
+fn number(arg: Option<String>, flag: &str) -> Result<usize, String> {
+    arg.and_then(|arg| arg.parse().ok())
+        .ok_or_else(|| format!("{flag} expects a number"))
+}
+
+impl Command {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        match args.next().as_deref() {
+            Some("list") => match args.next() {
+                None => Ok(Command::List),
+                Some(arg) => Err(format!("unexpected argument '{arg}'")),
+            },
+            Some("run") => {
+                let mut year = None;
+                let mut day = None;
+                let mut all = false;
+                let mut parts = Part::ALL.to_vec();
+                let mut input = None;
+                let mut record = false;
+                let mut verify = false;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--all" => all = true,
+                        "--record" => record = true,
+                        "--verify" => verify = true,
+                        "--input" => input = Some(args.next().ok_or("--input expects a file")?),
+                        "--part" => parts = only_part(args.next())?,
+                        _ => year_or_day(&arg, &mut year, &mut day)?,
+                    }
+                }
+
+                if verify {
+                    return match day.is_none() && !all && !record && input.is_none() {
+                        true if parts == Part::ALL => Ok(Command::Verify { year }),
+                        _ => {
+                            Err("--verify checks every part of every day, only takes a year"
+                                .to_owned())
+                        }
+                    };
+                }
+
+                let (year, day) = selection(year, day, all)?;
+
+                match day {
+                    None if input.is_some() => Err("--input is for a single day".to_owned()),
+                    _ if record && input.is_some() => {
+                        Err("--record is for the days' own inputs, drop --input".to_owned())
+                    }
+                    _ => Ok(Command::Run {
+                        year,
+                        day,
+                        parts,
+                        input,
+                        record,
+                    }),
+                }
+            }
+            Some("bench") => {
+                let mut year = None;
+                let mut day = None;
+                let mut all = false;
+                let mut parts = Part::ALL.to_vec();
+                let mut options = Options::default();
+                let mut format = Format::Table;
+                let mut output = None;
+                let mut history = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--all" => all = true,
+                        "--part" => parts = only_part(args.next())?,
+                        "--warmup" => options.warmup = number(args.next(), "--warmup")?,
+                        "--runs" => match number(args.next(), "--runs")? {
+                            0 => return Err("--runs expects at least one run".to_owned()),
+                            runs => options.runs = Runs::Count(runs),
+                        },
+                        "--time" => {
+                            let ms = number(args.next(), "--time")?;
+
+                            options.runs = Runs::Time(Duration::from_millis(ms as u64));
+                        }
+                        "--json" => format = Format::Json,
+                        "--csv" => format = Format::Csv,
+                        "--output" => output = Some(args.next().ok_or("--output expects a file")?),
+                        "--save" => {
+                            history.get_or_insert_with(|| history::HISTORY.to_owned());
+                        }
+                        "--history" => {
+                            history = Some(args.next().ok_or("--history expects a file")?)
+                        }
+                        _ => year_or_day(&arg, &mut year, &mut day)?,
+                    }
+                }
+
+                let (year, day) = selection(year, day, all)?;
+
+                match (format, &output) {
+                    (Format::Table, Some(_)) => {
+                        Err("--output writes a report, pick --json or --csv".to_owned())
+                    }
+                    _ => Ok(Command::Bench {
+                        year,
+                        day,
+                        parts,
+                        options,
+                        format,
+                        output,
+                        history,
+                    }),
+                }
+            }
+            Some("report") => {
+                let mut year = None;
+                let mut history = history::HISTORY.to_owned();
+                let mut threshold = 10;
+                let mut machine = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--history" => history = args.next().ok_or("--history expects a file")?,
+                        "--threshold" => threshold = number(args.next(), "--threshold")?,
+                        "--machine" => {
+                            machine = Some(args.next().ok_or("--machine expects a name")?)
+                        }
+                        _ if year.is_none() => {
+                            year = Some(arg.parse().map_err(|_| format!("invalid year '{arg}'"))?)
+                        }
+                        _ => return Err(format!("unexpected argument '{arg}'")),
+                    }
+                }
+
+                Ok(Command::Report {
+                    year,
+                    history,
+                    threshold,
+                    machine,
+                })
+            }
+            Some(command) => Err(format!("unknown command '{command}'")),
+            None => Err("missing command".to_owned()),
+        }
+    }
+}
+
+fn solvers() -> Vec<Solver> {
+    [
+        year2021::solvers(),
+        year2022::solvers(),
+        year2023::solvers(),
+    ]
+    .concat()
+}
+
+fn as_ms(duration: Duration) -> String {
+    format!("{:.4}ms", duration.as_secs_f32() * 1000.)
+}
+
+/// Prints rows under the header, padding every column to its widest cell.
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let widths = rows.iter().fold(header.map(str::len), |mut widths, row| {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+
+        widths
+    });
+    let print_row = |row: [&str; N]| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+
+        println!("{}", cells.join(" | ").trim_end());
+    };
+
+    print_row(header);
+    println!("{}", widths.map(|width| "-".repeat(width)).join("-+-"));
+    for row in rows {
+        print_row(row.each_ref().map(String::as_str));
+    }
+}
+
+fn list(solvers: &[Solver]) {
+    let rows: Vec<_> = solvers
+        .iter()
+        .map(|solver| {
+            [
+                solver.year.to_string(),
+                format!("{:02}", solver.day),
+                solver.title.to_owned(),
+            ]
+        })
+        .collect();
+
+    print_table(["Year", "Day", "Title"], &rows);
+}
+
+/// Recorded answers of each year, by answers file.
+type Store = BTreeMap<&'static str, Answers>;
+
+/// Loads the answers files of the solvers' years.
+fn load_answers(solvers: &[Solver]) -> Result<Store, String> {
+    solvers
+        .iter()
+        .map(|solver| solver.answers)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|path| {
+            Answers::load(path)
+                .map(|answers| (path, answers))
+                .map_err(|err| format!("{path}: {}", err.diagnostic()))
+        })
+        .collect()
+}
+
+type Results<'a> = Vec<(&'a Solver, Result<Report, SolutionError>)>;
+
+/// Solves each day with its registered input, or the given one.
+fn solve<'a>(solvers: &'a [Solver], parts: &[Part], input: Option<&str>) -> Results<'a> {
+    solvers
+        .iter()
+        .map(|solver| {
+            let report = match input {
+                Some(path) => read_source(path).and_then(|input| solver.solve(&input, parts)),
+                None => solver.run(parts),
+            };
+
+            (solver, report)
+        })
+        .collect()
+}
+
+/// The answered parts of a report.
+fn answers(report: &Report) -> impl Iterator<Item = (Part, &Answer)> {
+    [(Part::One, &report.part1), (Part::Two, &report.part2)]
+        .into_iter()
+        .filter_map(|(part, answer)| Some((part, answer.as_ref()?)))
+}
+
+/// Prints one row per day; multi-line answers, invalid inputs and answers differing from the
+/// recorded ones are printed after the table.
+fn run(results: &Results, store: Option<&Store>) -> bool {
+    let mut ok = true;
+    let mut drawings = vec![];
+    let mut diagnostics = vec![];
+    let rows: Vec<_> = results
+        .iter()
+        .map(|(solver, report)| {
+            let (year, day) = (solver.year.to_string(), format!("{:02}", solver.day));
+            let title = solver.title.to_owned();
+
+            match report {
+                Ok(report) => {
+                    let total = as_ms(report.total());
+                    let [part1, part2] = [(Part::One, &report.part1), (Part::Two, &report.part2)]
+                        .map(|(part, answer)| match answer {
+                            None => "-".to_owned(),
+                            Some(Answer {
+                                value: Err(SolutionError::NoAnswer),
+                                time,
+                            }) => format!("none ({})", as_ms(*time)),
+                            Some(Answer {
+                                value: Err(err), ..
+                            }) => {
+                                ok = false;
+                                format!("error: {err}")
+                            }
+                            Some(Answer {
+                                value: Ok(value),
+                                time,
+                            }) => {
+                                let mut cell = match value.contains('\n') {
+                                    true => {
+                                        drawings.push((solver, part, value));
+                                        "see below".to_owned()
+                                    }
+                                    false => value.to_owned(),
+                                };
+
+                                let checked = store
+                                    .and_then(|store| store.get(solver.answers))
+                                    .map(|answers| answers.check(solver.day, part, value));
+                                if let Some(Err(err)) = checked {
+                                    ok = false;
+                                    diagnostics.push((solver, err.to_string()));
+                                    cell = format!("wrong: {cell}");
+                                }
+
+                                format!("{cell} ({})", as_ms(*time))
+                            }
+                        });
+
+                    [year, day, title, part1, part2, total]
+                }
+                Err(err) => {
+                    ok = false;
+                    if let SolutionError::InvalidInput(_) = err {
+                        diagnostics.push((solver, err.diagnostic()));
+                    }
+
+                    [
+                        year,
+                        day,
+                        title,
+                        format!("error: {err}"),
+                        "-".to_owned(),
+                        "-".to_owned(),
+                    ]
+                }
+            }
+        })
+        .collect();
+
+    print_table(["Year", "Day", "Title", "Part 1", "Part 2", "Total"], &rows);
+
+    for (solver, part, value) in drawings {
+        println!(
+            "\n{} Day {:02}, part {part}:\n{value}",
+            solver.year, solver.day
+        );
+    }
+
+    for (solver, diagnostic) in diagnostics {
+        eprintln!("\n{} Day {:02}: {diagnostic}", solver.year, solver.day);
+    }
+
+    ok
+}
+
+/// Checks the answers against the recorded ones, printing the wrong ones and a summary.
+///
+/// Days without an input are skipped, their answers can't be known.
+fn verify(results: &Results, store: &Store) -> bool {
+    let (mut verified, mut wrong, mut unrecorded, mut errors, mut skipped) = (0, 0, 0, 0, 0);
+
+    for (solver, report) in results {
+        let report = match report {
+            Ok(report) => report,
+            Err(SolutionError::MissingInput(_)) => {
+                skipped += 1;
+                continue;
+            }
+            Err(err) => {
+                errors += 1;
+                eprintln!(
+                    "{} Day {:02}: {}",
+                    solver.year,
+                    solver.day,
+                    err.diagnostic()
+                );
+                continue;
+            }
+        };
+        let recorded = store.get(solver.answers);
+
+        for (part, answer) in answers(report) {
+            match &answer.value {
+                Ok(value) => {
+                    match recorded.filter(|answers| answers.get(solver.day, part).is_some()) {
+                        None => unrecorded += 1,
+                        Some(answers) => match answers.check(solver.day, part, value) {
+                            Ok(()) => verified += 1,
+                            Err(err) => {
+                                wrong += 1;
+                                eprintln!("{} Day {:02}: {err}", solver.year, solver.day);
+                            }
+                        },
+                    }
+                }
+                Err(SolutionError::NoAnswer) => {}
+                Err(err) => {
+                    errors += 1;
+                    eprintln!("{} Day {:02}, part {part}: {err}", solver.year, solver.day);
+                }
+            }
+        }
+    }
+
+    println!(
+        "{verified} verified, {wrong} wrong, {unrecorded} not recorded, {errors} errors, \
+         {skipped} days without an input"
+    );
+
+    wrong == 0 && errors == 0
+}
+
+/// Asks whether to record each answer differing from the recorded one, then saves the changed
+/// answers files. Returns how many answers were recorded.
+fn record(
+    results: &Results,
+    store: &mut Store,
+    mut confirm: impl FnMut(&str) -> bool,
+) -> std::io::Result<usize> {
+    let mut changed = BTreeSet::new();
+    let mut count = 0;
+
+    for (solver, report) in results {
+        let Ok(report) = report else { continue };
+
+        for (part, answer) in answers(report) {
+            let Ok(value) = &answer.value else { continue };
+            let answers = store.entry(solver.answers).or_default();
+            let question = match answers.get(solver.day, part) {
+                Some(recorded) if recorded == value => continue,
+                Some(recorded) => format!(
+                    "{} Day {:02}, part {part}: replace '{recorded}' with '{value}'?",
+                    solver.year, solver.day
+                ),
+                None => format!(
+                    "{} Day {:02}, part {part}: record '{value}'?",
+                    solver.year, solver.day
+                ),
+            };
+
+            if confirm(&question) {
+                answers.insert(solver.day, part, value.to_owned());
+                changed.insert(solver.answers);
+                count += 1;
+            }
+        }
+    }
+
+    for path in changed {
+        store[path].save(path)?;
+    }
+
+    Ok(count)
+}
+
+/// Benchmarks the days with their registered inputs, reporting those that fail.
+fn bench(solvers: &[Solver], parts: &[Part], options: &Options) -> (Vec<Benchmark>, bool) {
+    let mut ok = true;
+    let benchmarks = solvers
+        .iter()
+        .flat_map(|solver| {
+            let benchmarks = (solver.input)()
+                .and_then(|input| aoc::bench::bench(solver, &input, parts, options));
+
+            benchmarks.unwrap_or_else(|err| {
+                ok = false;
+                eprintln!(
+                    "{} Day {:02}: {}",
+                    solver.year,
+                    solver.day,
+                    err.diagnostic()
+                );
+                vec![]
+            })
+        })
+        .collect();
+
+    (benchmarks, ok)
+}
+
+fn print_benchmarks(benchmarks: &[Benchmark]) {
+    let rows: Vec<_> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let stats = &benchmark.stats;
+
+            [
+                benchmark.year.to_string(),
+                format!("{:02}", benchmark.day),
+                benchmark.title.to_owned(),
+                benchmark.step.to_string(),
+                stats.runs.to_string(),
+                as_ms(stats.min),
+                as_ms(stats.median),
+                as_ms(stats.p95),
+                stats
+                    .allocations
+                    .map_or("-".to_owned(), |count| count.to_string()),
+            ]
+        })
+        .collect();
+
+    print_table(
+        [
+            "Year",
+            "Day",
+            "Title",
+            "Step",
+            "Runs",
+            "Min",
+            "Median",
+            "P95",
+            "Allocations",
+        ],
+        &rows,
+    );
+}
+
+/// Compact duration, in the most fitting unit.
+fn short(duration: Duration) -> String {
+    match duration.as_nanos() {
+        ns if ns < 1_000 => format!("{ns}ns"),
+        ns if ns < 1_000_000 => format!("{:.1}µs", ns as f64 / 1e3),
+        ns if ns < 1_000_000_000 => format!("{:.2}ms", ns as f64 / 1e6),
+        ns => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+/// Prints the trend of each day benchmarked on the machine, flagging those whose median
+/// regressed by more than the threshold since the previous commit.
+fn report(entries: &[history::Entry], year: Option<u16>, machine: &str, threshold: usize) -> bool {
+    const SHOWN: usize = 5;
+
+    let trends: Vec<_> = history::trends(entries, machine)
+        .into_iter()
+        .filter(|trend| year.is_none_or(|year| trend.year == year))
+        .collect();
+
+    if trends.is_empty() {
+        eprintln!("no benchmarks of '{machine}' in the history, see aoc bench --save");
+        return false;
+    }
+
+    let mut regressions = 0;
+    let rows: Vec<_> = trends
+        .iter()
+        .map(|trend| {
+            let (commit, median) = trend.medians.last().expect("a benchmarked commit");
+            let shown = &trend.medians[trend.medians.len().saturating_sub(SHOWN)..];
+            let change = match trend.change() {
+                Some(change) if change > threshold as f64 => {
+                    regressions += 1;
+                    format!("{change:+.1}% regressed")
+                }
+                Some(change) => format!("{change:+.1}%"),
+                None => "-".to_owned(),
+            };
+
+            [
+                trend.year.to_string(),
+                format!("{:02}", trend.day),
+                trend.step.clone(),
+                trend.medians.len().to_string(),
+                shown
+                    .iter()
+                    .map(|(_, median)| short(*median))
+                    .collect::<Vec<_>>()
+                    .join(" → "),
+                format!("{} ({commit})", short(*median)),
+                change,
+            ]
+        })
+        .collect();
+
+    print_table(
+        [
+            "Year", "Day", "Step", "Commits", "Trend", "Median", "Change",
+        ],
+        &rows,
+    );
+    println!("\n{regressions} regressions above {threshold}% on {machine}");
+
+    regressions == 0
+}
+
+/// Asks a yes/no question on stdin, defaulting to no.
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    std::io::stdout().flush().ok();
+
+    let mut answer = String::new();
+
+    std::io::stdin().read_line(&mut answer).is_ok() && answer.trim().eq_ignore_ascii_case("y")
+}
+
+fn main() -> ExitCode {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let solvers = solvers();
+    let select = |year: Option<u16>, day: Option<u8>| -> Vec<Solver> {
+        solvers
+            .iter()
+            .filter(|solver| year.is_none_or(|year| solver.year == year))
+            .filter(|solver| day.is_none_or(|day| solver.day == day))
+            .copied()
+            .collect()
+    };
+    let select_days = |year: u16, day: Option<u8>| -> Option<Vec<Solver>> {
+        let selected = select(Some(year), day);
+
+        if selected.is_empty() {
+            match day {
+                Some(day) => eprintln!("no solution registered for {year} day {day:02}"),
+                None => eprintln!("no solution registered for {year}"),
+            }
+        }
+
+        (!selected.is_empty()).then_some(selected)
+    };
+
+    let ok = match command {
+        Command::List => {
+            list(&solvers);
+            true
+        }
+        Command::Run {
+            year,
+            day,
+            parts,
+            input,
+            record: recording,
+        } => {
+            let Some(selected) = select_days(year, day) else {
+                return ExitCode::FAILURE;
+            };
+            let mut store = match load_answers(&selected) {
+                Ok(store) => store,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let results = solve(&selected, &parts, input.as_deref());
+            // answers about to be recorded are not wrong yet
+            let ok = run(&results, (input.is_none() && !recording).then_some(&store));
+
+            match recording {
+                true => match record(&results, &mut store, confirm) {
+                    Ok(count) => {
+                        println!("recorded {count} answers");
+                        ok
+                    }
+                    Err(err) => {
+                        eprintln!("error: could not save the answers: {err}");
+                        false
+                    }
+                },
+                false => ok,
+            }
+        }
+        Command::Verify { year } => {
+            let selected = select(year, None);
+
+            match load_answers(&selected) {
+                Ok(store) => verify(&solve(&selected, &Part::ALL, None), &store),
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    false
+                }
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            parts,
+            options,
+            format,
+            output,
+            history,
+        } => {
+            let Some(selected) = select_days(year, day) else {
+                return ExitCode::FAILURE;
+            };
+            let (benchmarks, mut ok) = bench(&selected, &parts, &options);
+
+            if let Some(path) = history {
+                let (commit, machine) = (history::commit(), history::machine());
+
+                match history::append(&path, &commit, &machine, &benchmarks) {
+                    Ok(()) => eprintln!(
+                        "saved {} benchmarks of {commit} on {machine} to '{path}'",
+                        benchmarks.len()
+                    ),
+                    Err(err) => {
+                        eprintln!("error: could not save the benchmarks to '{path}': {err}");
+                        ok = false;
+                    }
+                }
+            }
+
+            let report = match format {
+                Format::Table => {
+                    print_benchmarks(&benchmarks);
+                    None
+                }
+                Format::Json => Some(aoc::bench::json(&benchmarks)),
+                Format::Csv => Some(aoc::bench::csv(&benchmarks)),
+            };
+
+            match (report, output) {
+                (Some(report), Some(path)) => match std::fs::write(&path, report) {
+                    Ok(()) => ok,
+                    Err(err) => {
+                        eprintln!("error: could not write the report to '{path}': {err}");
+                        false
+                    }
+                },
+                (Some(report), None) => {
+                    print!("{report}");
+                    ok
+                }
+                (None, _) => ok,
+            }
+        }
+        Command::Report {
+            year,
+            history,
+            threshold,
+            machine,
+        } => {
+            let entries = std::fs::read_to_string(&history)
+                .map_err(|err| format!("could not read '{history}': {err}"))
+                .and_then(|text| history::parse(&text).map_err(|err| err.diagnostic()));
+
+            match entries {
+                Ok(entries) => {
+                    let machine = machine.unwrap_or_else(history::machine);
+
+                    report(&entries, year, &machine, threshold)
+                }
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    false
+                }
+            }
+        }
+    };
+
+    match ok {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        Command::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(Ok(Command::List), parse("list"));
+        assert_eq!(
+            Ok(Command::Run {
+                year: 2023,
+                day: Some(17),
+                parts: vec![Part::Two],
+                input: None,
+                record: false
+            }),
+            parse("run 2023 17 --part 2")
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                year: 2022,
+                day: None,
+                parts: Part::ALL.to_vec(),
+                input: None,
+                record: true
+            }),
+            parse("run 2022 --all --record")
+        );
+        assert_eq!(
+            Ok(Command::Run {
+                year: 2021,
+                day: Some(1),
+                parts: Part::ALL.to_vec(),
+                input: Some("-".to_owned()),
+                record: false
+            }),
+            parse("run 2021 1 --input -")
+        );
+        assert_eq!(Ok(Command::Verify { year: None }), parse("run --verify"));
+        assert_eq!(
+            Ok(Command::Verify { year: Some(2022) }),
+            parse("run 2022 --verify")
+        );
+        assert_eq!(
+            Ok(Command::Bench {
+                year: 2021,
+                day: None,
+                parts: vec![Part::One],
+                options: Options {
+                    warmup: 0,
+                    runs: Runs::Time(Duration::from_millis(200))
+                },
+                format: Format::Csv,
+                output: Some("2021.csv".to_owned()),
+                history: None
+            }),
+            parse("bench 2021 --all --part 1 --warmup 0 --time 200 --csv --output 2021.csv")
+        );
+        assert!(matches!(
+            parse("bench 2021 3 --save"),
+            Ok(Command::Bench { history: Some(history), .. }) if history == history::HISTORY
+        ));
+        assert_eq!(
+            Ok(Command::Report {
+                year: Some(2022),
+                history: "old.csv".to_owned(),
+                threshold: 5,
+                machine: None
+            }),
+            parse("report 2022 --threshold 5 --history old.csv")
+        );
+    }
+
+    #[test]
+    fn invalid_commands() {
+        assert!(parse("").is_err());
+        assert!(parse("run 2022").is_err());
+        assert!(parse("run 2022 3 --all").is_err());
+        assert!(parse("run 2022 3 --part 3").is_err());
+        assert!(parse("run twenty").is_err());
+        assert!(parse("run 2021 --all --input day01.txt").is_err());
+        assert!(parse("run 2021 1 --input").is_err());
+        assert!(parse("run 2021 1 --input day01.txt --record").is_err());
+        assert!(parse("run 2021 1 --verify").is_err());
+        assert!(parse("run --verify --part 1").is_err());
+        assert!(parse("bench 2021").is_err());
+        assert!(parse("bench 2021 1 --runs 0").is_err());
+        assert!(parse("bench 2021 1 --time soon").is_err());
+        assert!(parse("bench 2021 1 --output 2021.csv").is_err());
+        assert!(parse("report 2021 2022").is_err());
+        assert!(parse("report --threshold").is_err());
+    }
+
+    #[test]
+    fn registered() {
+        let solvers = solvers();
+
+        for year in [2021, 2022, 2023] {
+            assert!(solvers.iter().any(|solver| solver.year == year));
+        }
+        assert!(solvers.iter().all(|solver| (1..=25).contains(&solver.day)));
+    }
+
+    #[test]
+    fn record_answers() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        let path: &'static str = String::leak(path.to_string_lossy().into_owned());
+        let solver = Solver {
+            answers: path,
+            ..solvers()[0]
+        };
+        let answer = |value: &str| Answer {
+            value: Ok(value.to_owned()),
+            time: Duration::ZERO,
+        };
+        let results = vec![(
+            &solver,
+            Ok(Report {
+                parse: Duration::ZERO,
+                part1: Some(answer("1")),
+                part2: Some(answer("2")),
+            }),
+        )];
+        let mut store = Store::new();
+        let mut questions = vec![];
+
+        let recorded = record(&results, &mut store, |question| {
+            questions.push(question.to_owned());
+            question.contains("part 1")
+        });
+
+        assert_eq!(Ok(1), recorded.map_err(|err| err.to_string()));
+        assert_eq!(2, questions.len());
+        assert_eq!(Ok(store[path].clone()), Answers::load(path));
+        assert_eq!(Some("1"), store[path].get(solver.day, Part::One));
+        assert!(verify(&results, &store));
+
+        store
+            .get_mut(path)
+            .unwrap()
+            .insert(solver.day, Part::One, "3".to_owned());
+        assert!(!verify(&results, &store));
+
+        std::fs::remove_file(path).expect("recorded answers");
+    }
+}
@@ -0,0 +1,235 @@
+//! Known-correct answers of a year, compared against when running its days.
+//!
+//! They are stored next to the inputs, as `answers.toml` in the year crate's directory:
+//!
+//! ```toml
+//! [day01]
+//! part1 = "24000"
+//! part2 = "45000"
+//! ```
+//!
+//! Only this subset of TOML is supported: one `[dayNN]` table per day, with string or integer
+//! answers. Multi-line answers are written with `\n` escapes.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::solution::{Part, Result, SolutionError};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, Part), String>);
+
+impl Answers {
+    /// Reads the answers file at `path`, without answers when there is none.
+    pub fn load(path: &str) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => text.parse(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(_) => Err(SolutionError::MissingInput(path.to_owned())),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Records an answer, returning the previous one.
+    pub fn insert(&mut self, day: u8, part: Part, answer: String) -> Option<String> {
+        self.0.insert((day, part), answer)
+    }
+
+    /// Compares an answer to the recorded one, if any.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<()> {
+        match self.get(day, part) {
+            Some(expected) if expected != answer => Err(SolutionError::WrongAnswer {
+                part,
+                expected: expected.to_owned(),
+                actual: answer.to_owned(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The answers to compare against: those at `path`, or none when the input is given with
+/// `--input`, as they are only known for the day's own input.
+pub fn answers_from_args(args: impl IntoIterator<Item = String>, path: &str) -> Result<Answers> {
+    match args.into_iter().any(|arg| arg == "--input") {
+        true => Ok(Answers::default()),
+        false => Answers::load(path),
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_owned(),
+            '"' => "\\\"".to_owned(),
+            '\n' => "\\n".to_owned(),
+            '\r' => "\\r".to_owned(),
+            '\t' => "\\t".to_owned(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn unescape(text: &str) -> Option<String> {
+    let mut chars = text.chars();
+    let mut answer = String::new();
+
+    while let Some(c) = chars.next() {
+        answer.push(match c {
+            '\\' => match chars.next()? {
+                '\\' => '\\',
+                '"' => '"',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                _ => return None,
+            },
+            '"' => return None,
+            c => c,
+        });
+    }
+
+    Some(answer)
+}
+
+impl FromStr for Answers {
+    type Err = SolutionError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for line in input.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                let table = line
+                    .strip_prefix("[day")
+                    .and_then(|table| table.strip_suffix(']'))
+                    .and_then(|table| table.parse().ok())
+                    .filter(|table| (1..=25).contains(table))
+                    .ok_or_else(|| SolutionError::expected(input, line, "a table '[dayNN]'"))?;
+
+                day = Some(table);
+                continue;
+            }
+
+            let day =
+                day.ok_or_else(|| SolutionError::expected(input, line, "a table '[dayNN]'"))?;
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                SolutionError::expected(input, line, "an answer 'part1 = \"...\"'")
+            })?;
+            let (key, value) = (key.trim(), value.trim());
+            let part = match key {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(SolutionError::expected(input, key, "'part1' or 'part2'")),
+            };
+            let answer = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(quoted) => unescape(quoted),
+                None => value.parse::<i128>().ok().map(|_| value.to_owned()),
+            }
+            .ok_or_else(|| SolutionError::expected(input, value, "a quoted answer"))?;
+
+            answers.insert(day, part, answer);
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut table = None;
+
+        for (&(day, part), answer) in &self.0 {
+            if table != Some(day) {
+                if table.is_some() {
+                    writeln!(f)?;
+                }
+
+                writeln!(f, "[day{day:02}]")?;
+                table = Some(day);
+            }
+
+            writeln!(f, "part{part} = \"{}\"", escape(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::default();
+
+        answers.insert(2, Part::Two, "#.\n.#".to_owned());
+        answers.insert(1, Part::One, "24000".to_owned());
+        answers.insert(1, Part::Two, "say \"hi\"".to_owned());
+
+        let text = answers.to_string();
+
+        assert_eq!(
+            "[day01]\npart1 = \"24000\"\npart2 = \"say \\\"hi\\\"\"\n\n[day02]\npart2 = \"#.\\n.#\"\n",
+            text
+        );
+        assert_eq!(Ok(answers), text.parse());
+    }
+
+    #[test]
+    fn parse() {
+        let answers: Answers = "# 2022\n[day05]\npart1 = 42\n  part2 = \"CMZ\"\n"
+            .parse()
+            .expect("valid answers");
+
+        assert_eq!(Some("42"), answers.get(5, Part::One));
+        assert_eq!(Some("CMZ"), answers.get(5, Part::Two));
+        assert_eq!(None, answers.get(6, Part::One));
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid = |text: &str| match text.parse::<Answers>() {
+            Err(SolutionError::InvalidInput(err)) => (err.line, err.text),
+            answers => panic!("expected invalid answers, got {answers:?}"),
+        };
+
+        assert_eq!((1, "part1 = \"1\"".to_owned()), invalid("part1 = \"1\""));
+        assert_eq!((2, "[day26]".to_owned()), invalid("[day01]\n[day26]"));
+        assert_eq!((2, "part3".to_owned()), invalid("[day01]\npart3 = \"1\""));
+        assert_eq!((2, "\"1".to_owned()), invalid("[day01]\npart1 = \"1"));
+    }
+
+    #[test]
+    fn check() {
+        let answers: Answers = "[day01]\npart1 = \"3\"".parse().expect("valid answers");
+
+        assert_eq!(Ok(()), answers.check(1, Part::One, "3"));
+        assert_eq!(Ok(()), answers.check(1, Part::Two, "4"));
+        assert_eq!(
+            Err(SolutionError::WrongAnswer {
+                part: Part::One,
+                expected: "3".to_owned(),
+                actual: "4".to_owned()
+            }),
+            answers.check(1, Part::One, "4")
+        );
+    }
+}
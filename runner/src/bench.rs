@@ -0,0 +1,322 @@
+//! Repeated timings of the registered solutions, to compare them before and after a change.
+//!
+//! Each step, the parsing then the parts, is warmed up, then run a number of times or for a time
+//! budget. Allocations are counted when the binary uses the [`Counting`] allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::registry::Solver;
+use crate::solution::{normalize, Part, Result, SolutionError};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator counting the allocations, for [`Stats::allocations`].
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: aoc::bench::Counting = aoc::bench::Counting;
+/// ```
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Allocations so far, `None` without the [`Counting`] allocator: any binary allocates before
+/// benchmarking.
+fn allocations() -> Option<u64> {
+    match ALLOCATIONS.load(Ordering::Relaxed) {
+        0 => None,
+        count => Some(count),
+    }
+}
+
+/// How many times each step is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Runs {
+    Count(usize),
+    /// As many runs as fit in the budget, at least one.
+    Time(Duration),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Runs before measuring, not counted.
+    pub warmup: usize,
+    pub runs: Runs,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: Runs::Time(Duration::from_secs(1)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    /// Allocations of a run on average, formatting the answer of a part included.
+    pub allocations: Option<u64>,
+}
+
+impl Stats {
+    /// Nearest-rank statistics of the samples, of which there must be at least one.
+    pub fn of(mut samples: Vec<Duration>, allocations: Option<u64>) -> Self {
+        samples.sort();
+
+        let rank = |percent: usize| samples[(samples.len() * percent).div_ceil(100).max(1) - 1];
+
+        Self {
+            runs: samples.len(),
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+            allocations,
+        }
+    }
+}
+
+/// Warms `f` up, then measures it as many times as the options say.
+fn measure<T>(options: &Options, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(match options.runs {
+        Runs::Count(count) => count,
+        Runs::Time(_) => 1024,
+    });
+    let mut allocated = Some(0);
+    let started = Instant::now();
+
+    while match options.runs {
+        Runs::Count(count) => samples.len() < count,
+        Runs::Time(budget) => samples.is_empty() || started.elapsed() < budget,
+    } {
+        let before = allocations();
+        let start = Instant::now();
+        let value = black_box(f());
+        let elapsed = start.elapsed();
+        let after = allocations();
+
+        samples.push(elapsed);
+        allocated = allocated
+            .zip(before.zip(after))
+            .map(|(total, (before, after))| total + after - before);
+        drop(value);
+    }
+
+    let allocations = allocated.map(|total| total / samples.len() as u64);
+
+    Stats::of(samples, allocations)
+}
+
+/// A step of a day's solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+/// Benchmarks the parsing of the [`normalize`]d input, then each of the requested parts.
+///
+/// Parts without an answer are measured too, other errors stop the benchmark.
+pub fn bench(
+    solver: &Solver,
+    input: &str,
+    parts: &[Part],
+    options: &Options,
+) -> Result<Vec<Benchmark>> {
+    let input = normalize(input);
+    let parsed = (solver.parse)(&input)?;
+    let benchmark = |step, stats| Benchmark {
+        year: solver.year,
+        day: solver.day,
+        title: solver.title,
+        step,
+        stats,
+    };
+    let mut benchmarks = vec![benchmark(
+        Step::Parse,
+        measure(options, || (solver.parse)(&input)),
+    )];
+
+    for &part in parts {
+        let solve = match part {
+            Part::One => solver.part1,
+            Part::Two => solver.part2,
+        };
+
+        match solve(&parsed) {
+            Ok(_) | Err(SolutionError::NoAnswer) => {}
+            Err(err) => return Err(err),
+        }
+
+        benchmarks.push(benchmark(
+            Step::Part(part),
+            measure(options, || solve(&parsed)),
+        ));
+    }
+
+    Ok(benchmarks)
+}
+
+/// Columns of the [`csv`] reports.
+pub const COLUMNS: [&str; 8] = [
+    "year",
+    "day",
+    "step",
+    "runs",
+    "min_ns",
+    "median_ns",
+    "p95_ns",
+    "allocations",
+];
+
+impl Benchmark {
+    /// The benchmark as a line of [`csv`], without its line ending.
+    pub fn csv_row(&self) -> String {
+        let stats = &self.stats;
+
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.year,
+            self.day,
+            self.step,
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.p95.as_nanos(),
+            stats
+                .allocations
+                .map_or(String::new(), |count| count.to_string()),
+        )
+    }
+}
+
+/// The benchmarks as CSV, one line per step, durations in nanoseconds.
+pub fn csv(benchmarks: &[Benchmark]) -> String {
+    let mut csv = COLUMNS.join(",") + "\n";
+
+    for benchmark in benchmarks {
+        csv += &benchmark.csv_row();
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// The benchmarks as a JSON array, one object per step, durations in nanoseconds.
+pub fn json(benchmarks: &[Benchmark]) -> String {
+    let objects: Vec<_> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let stats = &benchmark.stats;
+
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"title\": \"{}\", \"step\": \"{}\", \"runs\": {}, \
+                 \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}, \"allocations\": {}}}",
+                benchmark.year,
+                benchmark.day,
+                benchmark.title.replace('\\', "\\\\").replace('"', "\\\""),
+                benchmark.step,
+                stats.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos(),
+                stats
+                    .allocations
+                    .map_or("null".to_owned(), |count| count.to_string()),
+            )
+        })
+        .collect();
+
+    match objects.is_empty() {
+        true => "[]\n".to_owned(),
+        false => format!("[\n{}\n]\n", objects.join(",\n")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::of(samples, Some(2));
+
+        assert_eq!(20, stats.runs);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(10), stats.median);
+        assert_eq!(Duration::from_millis(19), stats.p95);
+
+        let single = Stats::of(vec![Duration::from_millis(3)], None);
+
+        assert_eq!((3, 3), (single.median.as_millis(), single.p95.as_millis()));
+    }
+
+    #[test]
+    fn runs() {
+        let mut calls = 0;
+        let options = Options {
+            warmup: 2,
+            runs: Runs::Count(5),
+        };
+
+        assert_eq!(5, measure(&options, || calls += 1).runs);
+        assert_eq!(7, calls);
+
+        let options = Options {
+            warmup: 0,
+            runs: Runs::Time(Duration::ZERO),
+        };
+
+        assert_eq!(1, measure(&options, || ()).runs);
+    }
+}
@@ -0,0 +1,71 @@
+//! Build script support for the year crates.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Binaries under `src/bin` registering days with [`run!`](crate::run) or
+/// [`register!`](crate::register), as module name and entry file.
+fn registered_bins(bins: &Path) -> Vec<(String, PathBuf)> {
+    let mut found: Vec<_> = fs::read_dir(bins)
+        .expect("failed to read src/bin")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+
+            if path.is_dir() {
+                let name = path.file_name()?.to_str()?.to_owned();
+
+                Some((name, path.join("main.rs")))
+            } else if path.extension()? == "rs" {
+                let name = path.file_stem()?.to_str()?.to_owned();
+
+                Some((name, path))
+            } else {
+                None
+            }
+        })
+        .filter(|(_, file)| {
+            fs::read_to_string(file).is_ok_and(|source| {
+                source.contains("aoc::run!") || source.contains("aoc::register!")
+            })
+        })
+        .collect();
+
+    found.sort();
+    found
+}
+
+/// Writes `$OUT_DIR/solvers.rs`, declaring every registered binary as a module and a `solvers()`
+/// listing all their days. Call it from `build.rs`, then include it at the root of the lib:
+///
+/// ```ignore
+/// include!(concat!(env!("OUT_DIR"), "/solvers.rs"));
+/// ```
+///
+/// The modules are left out of the lib's tests, the binaries already run them.
+pub fn register_bins() {
+    let manifest = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let out = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let bins = Path::new(&manifest).join("src").join("bin");
+    let days = registered_bins(&bins);
+
+    let modules: String = days
+        .iter()
+        .map(|(name, file)| {
+            format!(
+                "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {:?}]\nmod {name};\n\n",
+                file.display().to_string()
+            )
+        })
+        .collect();
+    let solvers: Vec<_> = days
+        .iter()
+        .map(|(name, _)| format!("{name}::solvers()"))
+        .collect();
+    let source = format!(
+        "{modules}/// Every registered day, by binary.\n#[cfg(not(test))]\npub fn solvers() -> Vec<aoc::registry::Solver> {{\n    [{}].concat()\n}}\n",
+        solvers.join(", ")
+    );
+
+    fs::write(Path::new(&out).join("solvers.rs"), source).expect("failed to write solvers.rs");
+    println!("cargo:rerun-if-changed={}", bins.display());
+}
@@ -0,0 +1,386 @@
+pub mod answers;
+pub mod bench;
+pub mod build;
+pub mod registry;
+pub mod solution;
+
+pub use solution::Solution;
+
+/// Reads the day's input and prints both answers, exiting on error.
+///
+/// The input is given with `--input <file>`, or `--input -` for stdin, and defaults to
+/// `inputs/dayNN.txt` in the crate's directory, whose answers are checked against the crate's
+/// `answers.toml`, see [`answers`].
+#[macro_export]
+macro_rules! solution {
+    ($day:ty) => {{
+        let result = $crate::solution::input_from_args(
+            std::env::args().skip(1),
+            env!("CARGO_MANIFEST_DIR"),
+            <$day as $crate::Solution>::DAY,
+        )
+        .and_then(|input| {
+            let answers = $crate::answers::answers_from_args(
+                std::env::args().skip(1),
+                concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml"),
+            )?;
+
+            <$day as $crate::Solution>::run_against(&input, &answers)
+        });
+
+        if let Err(err) = result {
+            eprintln!("error: {}", err.diagnostic());
+            std::process::exit(1);
+        }
+    }};
+}
+
+/// Reads the day's input like [`solution!`], then [`normalize`](solution::normalize)s it.
+#[macro_export]
+macro_rules! input {
+    ($day:ty) => {
+        $crate::solution::input_from_args(
+            std::env::args().skip(1),
+            env!("CARGO_MANIFEST_DIR"),
+            <$day as $crate::Solution>::DAY,
+        )
+        .map(|input| $crate::solution::normalize(&input).into_owned())
+    };
+}
+
+/// Generates a `main` running the days, see [`solution!`], and registers them.
+///
+/// Inputs are read at runtime, see [`solution!`], or from the given expression.
+#[macro_export]
+macro_rules! run {
+    ($($day:ty),+ $(,)?) => {
+        fn main() {
+            $($crate::solution!($day);)+
+        }
+
+        $crate::register!($($day),+);
+    };
+    ($($day:ty),+ => $input:expr) => {
+        fn main() {
+            $(
+                let result = $crate::answers::Answers::load(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/answers.toml"
+                ))
+                .and_then(|answers| <$day as $crate::Solution>::run_against($input, &answers));
+
+                if let Err(err) = result {
+                    eprintln!("error: {}", err.diagnostic());
+                    std::process::exit(1);
+                }
+            )+
+        }
+
+        $crate::register!($($day),+ => $input);
+    };
+}
+
+/// Declares `solvers()`, collected into the year crate's registry by [`build::register_bins`].
+///
+/// Inputs are read from `inputs/dayNN.txt` in the crate's directory, or from the given expression.
+/// Their answers are recorded in the crate's `answers.toml`.
+#[macro_export]
+macro_rules! register {
+    ($($day:ty),+ $(,)?) => {
+        #[allow(dead_code)]
+        pub(crate) fn solvers() -> Vec<$crate::registry::Solver> {
+            vec![$(
+                $crate::registry::Solver::of::<$day>(
+                    || {
+                        $crate::solution::read_input(
+                            env!("CARGO_MANIFEST_DIR"),
+                            <$day as $crate::Solution>::DAY,
+                        )
+                    },
+                    concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml"),
+                )
+            ),+]
+        }
+    };
+    ($($day:ty),+ => $input:expr) => {
+        #[allow(dead_code)]
+        pub(crate) fn solvers() -> Vec<$crate::registry::Solver> {
+            vec![$(
+                $crate::registry::Solver::of::<$day>(
+                    || Ok(String::from($input)),
+                    concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml"),
+                )
+            ),+]
+        }
+    };
+}
+
+/// Sanity checks shared by every day.
+#[macro_export]
+macro_rules! test_common {
+    ($day:ident) => {
+        #[test]
+        fn metadata() {
+            assert!((1..=25).contains(&$day::DAY));
+            assert!(!$day::TITLE.is_empty());
+        }
+    };
+}
+
+/// Tests both parts against one input, or several named cases.
+///
+/// Each input is [normalized](solution::normalize) as the runner does, and checked with both `\n`
+/// and `\r\n` line endings.
+///
+/// ```ignore
+/// aoc::test! {
+///     day_01:
+///     [simple] - "1\n2\n" => Some(3) => None;
+///     [empty] - "" => Some(0) => Some(0);
+/// }
+/// ```
+#[macro_export]
+macro_rules! test {
+    ($day:ident: - $input:expr => $p1:expr => $p2:expr) => {
+        #[test]
+        fn part1() {
+            $crate::solution::check($input, $day::parse, $day::part1, $p1);
+        }
+
+        #[test]
+        fn part2() {
+            $crate::solution::check($input, $day::parse, $day::part2, $p2);
+        }
+    };
+    ($day:ident: $([$case:ident] - $input:expr => $p1:expr => $p2:expr);* $(;)?) => {
+        $(
+            mod $case {
+                use super::*;
+
+                $crate::test!($day: - $input => $p1 => $p2);
+            }
+        )*
+    };
+}
+
+/// Declares the examples of a day as a test module, checked as by [`test!`].
+///
+/// ```ignore
+/// aoc::example! {
+///     [Day01]
+///     simple: "1\n2\n" => Some(3) => None
+/// }
+/// ```
+#[macro_export]
+macro_rules! example {
+    ([$day:ty] $($case:ident: $input:expr => $p1:expr => $p2:expr)*) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            $(
+                mod $case {
+                    use super::*;
+
+                    #[test]
+                    fn part1() {
+                        $crate::solution::check(
+                            $input,
+                            <$day as $crate::Solution>::parse,
+                            <$day as $crate::Solution>::part1,
+                            $p1,
+                        );
+                    }
+
+                    #[test]
+                    fn part2() {
+                        $crate::solution::check(
+                            $input,
+                            <$day as $crate::Solution>::parse,
+                            <$day as $crate::Solution>::part2,
+                            $p2,
+                        );
+                    }
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answers;
+    use crate::bench;
+    use crate::solution::{input_from_args, parse_in, Part, Result, SolutionError};
+    use crate::Solution;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const TITLE: &'static str = "Sum";
+        const YEAR: u16 = 2020;
+        const DAY: u8 = 1;
+        type Input = Vec<i32>;
+        type P1 = i32;
+        type P2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .lines()
+                .map(|line| parse_in(input, line.trim()))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::P1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::P2> {
+            input
+                .iter()
+                .max()
+                .map(|max| max.to_string())
+                .ok_or(SolutionError::NoAnswer)
+        }
+    }
+
+    use Sum as sum;
+
+    crate::test_common!(sum);
+
+    crate::test! {
+        sum:
+        [single] - "3" => Some(3) => Some("3");
+        [many] - "1\n2\n-4" => Some(-1) => Some("2");
+        [empty] - "" => Some(0) => None::<&str>;
+    }
+
+    crate::example! {
+        [Sum]
+        simple: "1\n2" => Some(3) => Some("2")
+    }
+
+    #[test]
+    fn parse_error() {
+        let Err(SolutionError::InvalidInput(err)) = Sum::parse("1\n2\n x2\n4") else {
+            panic!("expected invalid input")
+        };
+
+        assert_eq!((3, 2, 5..7), (err.line, err.column, err.span.clone()));
+        assert_eq!(
+            "expected i32, found 'x2' at line 3, column 2\n  |\n3 |  x2\n  |  ^^",
+            err.render()
+        );
+        assert!(Sum::run("x").is_err());
+    }
+
+    #[test]
+    fn no_answer() {
+        let report = Sum::solve("", &Part::ALL).expect("valid input");
+
+        assert_eq!(
+            Some(Ok("0".to_owned())),
+            report.part1.map(|answer| answer.value)
+        );
+        assert_eq!(
+            Some(Err(SolutionError::NoAnswer)),
+            report.part2.map(|answer| answer.value)
+        );
+        assert!(Sum::run("").is_ok());
+    }
+
+    #[test]
+    fn wrong_answer() {
+        let answers: Answers = "[day01]\npart1 = \"4\"\npart2 = \"2\""
+            .parse()
+            .expect("valid answers");
+
+        assert_eq!(
+            Err(SolutionError::WrongAnswer {
+                part: Part::One,
+                expected: "4".to_owned(),
+                actual: "3".to_owned()
+            }),
+            Sum::run_against("1\n2", &answers)
+        );
+        assert!(Sum::run_against("2\n2", &answers).is_ok());
+    }
+
+    #[test]
+    fn normalized_input() {
+        let input = "\u{feff}1\r\n2\r\n\r\n";
+        let report = Sum::solve(input, &[Part::One]).expect("normalized input");
+
+        assert!(Sum::parse(input).is_err());
+        assert_eq!(
+            Some(Ok("3".to_owned())),
+            report.part1.map(|answer| answer.value)
+        );
+    }
+
+    crate::register!(Sum => "1\n2\n3");
+
+    #[test]
+    fn registered() {
+        let [solver] = solvers()[..] else {
+            panic!("expected a single solver")
+        };
+        let report = solver.run(&[Part::Two]).expect("valid input");
+
+        assert_eq!((2020, 1, "Sum"), (solver.year, solver.day, solver.title));
+        assert_eq!(
+            Ok("6".to_owned()),
+            (solver.part1)(&(solver.parse)("1\n5").expect("valid input"))
+        );
+        assert_eq!(None, report.part1);
+        assert_eq!(
+            Some(Ok("3".to_owned())),
+            report.part2.map(|answer| answer.value)
+        );
+    }
+
+    #[test]
+    fn benchmarked() {
+        let options = bench::Options {
+            warmup: 1,
+            runs: bench::Runs::Count(3),
+        };
+        let benchmarks =
+            bench::bench(&solvers()[0], "1\n2", &Part::ALL, &options).expect("valid input");
+        let steps: Vec<_> = benchmarks.iter().map(|b| b.step.to_string()).collect();
+
+        assert_eq!(vec!["parse", "part1", "part2"], steps);
+        assert!(benchmarks.iter().all(|b| b.stats.runs == 3));
+        assert_eq!(
+            "year,day,step,runs,min_ns,median_ns,p95_ns,allocations",
+            bench::csv(&benchmarks).lines().next().unwrap()
+        );
+        assert_eq!(4, bench::csv(&benchmarks).lines().count());
+        assert!(bench::json(&benchmarks).contains("\"title\": \"Sum\", \"step\": \"part2\""));
+        assert!(bench::bench(&solvers()[0], "x", &Part::ALL, &options).is_err());
+    }
+
+    #[test]
+    fn input_arguments() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        let dir = env!("CARGO_MANIFEST_DIR");
+
+        assert!(input_from_args(args(&["--input", "Cargo.toml"]), dir, 1)
+            .is_ok_and(|input| input.contains("aoc-runner")));
+        assert_eq!(
+            Err(SolutionError::MissingInput(format!(
+                "{dir}/inputs/day01.txt"
+            ))),
+            input_from_args(args(&["--print"]), dir, 1)
+        );
+        assert!(matches!(
+            input_from_args(args(&["--input"]), dir, 1),
+            Err(SolutionError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn direct_test() {
+        Sum::test("1\n2", Some(3), Some("2".to_owned()));
+    }
+}
@@ -0,0 +1,84 @@
+use std::any::Any;
+
+use crate::solution::{normalize, timed, Part, Report, Result};
+use crate::Solution;
+
+/// Parsed input of a [`Solver`], downcast back to the `Solution::Input` by its parts.
+pub type Parsed = Box<dyn Any>;
+
+/// Type-erased entry point of a [`Solution`], as listed by each year crate's `solvers()`.
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub input: fn() -> Result<String>,
+    /// Path of the year's answers file, see [`crate::answers`].
+    pub answers: &'static str,
+    pub parse: fn(&str) -> Result<Parsed>,
+    pub part1: fn(&Parsed) -> Result<String>,
+    pub part2: fn(&Parsed) -> Result<String>,
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed>
+where
+    S::Input: 'static,
+{
+    S::parse(input).map(|input| Box::new(input) as Parsed)
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("the input was parsed by another solution")
+}
+
+fn part1<S: Solution>(input: &Parsed) -> Result<String>
+where
+    S::Input: 'static,
+{
+    S::part1(downcast::<S>(input)).map(|answer| answer.to_string())
+}
+
+fn part2<S: Solution>(input: &Parsed) -> Result<String>
+where
+    S::Input: 'static,
+{
+    S::part2(downcast::<S>(input)).map(|answer| answer.to_string())
+}
+
+impl Solver {
+    pub fn of<S: Solution>(input: fn() -> Result<String>, answers: &'static str) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            input,
+            answers,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    /// Parses the input and times the requested parts, see [`Solution::solve`].
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Report> {
+        timed(
+            || (self.parse)(&normalize(input)),
+            |input| (self.part1)(input),
+            |input| (self.part2)(input),
+            parts,
+        )
+    }
+
+    /// Reads the registered input, then solves it.
+    pub fn run(&self, parts: &[Part]) -> Result<Report> {
+        self.solve(&(self.input)()?, parts)
+    }
+}
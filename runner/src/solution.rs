@@ -0,0 +1,517 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Range;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::answers::Answers;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolutionError {
+    ParseError,
+    /// Part of the input could not be parsed, see [`InputError`].
+    InvalidInput(InputError),
+    /// The solution has no answer for this part.
+    NoAnswer,
+    MissingInput(String),
+    InvalidArgument(String),
+    /// The answer of a part differs from the recorded one, see [`crate::answers`].
+    WrongAnswer {
+        part: Part,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl Display for SolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolutionError::ParseError => write!(f, "failed to parse the input"),
+            SolutionError::InvalidInput(err) => write!(f, "{err}"),
+            SolutionError::NoAnswer => write!(f, "no answer"),
+            SolutionError::MissingInput(path) => write!(
+                f,
+                "could not read the input from '{path}', pass one with --input <file> (- for stdin)"
+            ),
+            SolutionError::InvalidArgument(message) => write!(f, "invalid argument: {message}"),
+            SolutionError::WrongAnswer {
+                part,
+                expected,
+                actual,
+            } => write!(f, "part {part} answered '{actual}', expected '{expected}'"),
+        }
+    }
+}
+
+/// Byte span of `text` in `input`, of which it must be a slice.
+fn span_of(input: &str, text: &str) -> Range<usize> {
+    let start = (text.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|start| start + text.len() <= input.len())
+        .expect("the text is not a slice of the input");
+
+    start..start + text.len()
+}
+
+impl SolutionError {
+    /// Invalid `text`, located by its position in `input`, of which it must be a slice.
+    pub fn invalid(input: &str, text: &str) -> Self {
+        SolutionError::InvalidInput(InputError::new(input, span_of(input, text), None))
+    }
+
+    /// Like [`SolutionError::invalid`], saying what was expected instead of `text`.
+    pub fn expected(input: &str, text: &str, expected: &str) -> Self {
+        SolutionError::InvalidInput(InputError::new(
+            input,
+            span_of(input, text),
+            Some(expected.to_owned()),
+        ))
+    }
+
+    /// Moves an error raised while parsing `text` to its position in `input`, of which it must
+    /// be a slice. A [`SolutionError::ParseError`] becomes invalid input covering all of `text`.
+    ///
+    /// ```
+    /// # use aoc::solution::SolutionError;
+    /// let input = "12\n3x\n";
+    /// let line = input.lines().nth(1).unwrap();
+    ///
+    /// let SolutionError::InvalidInput(err) = SolutionError::ParseError.within(input, line) else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!((2, 1, 3..5), (err.line, err.column, err.span));
+    /// ```
+    pub fn within(self, input: &str, text: &str) -> Self {
+        let span = span_of(input, text);
+
+        match self {
+            SolutionError::ParseError => {
+                SolutionError::InvalidInput(InputError::new(input, span, None))
+            }
+            SolutionError::InvalidInput(err) => SolutionError::InvalidInput(InputError::new(
+                input,
+                span.start + err.span.start..span.start + err.span.end,
+                err.expected,
+            )),
+            err => err,
+        }
+    }
+
+    /// Multi-line description of the error, rendering the invalid input if any.
+    pub fn diagnostic(&self) -> String {
+        match self {
+            SolutionError::InvalidInput(err) => err.render(),
+            err => err.to_string(),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {}
+
+pub type Result<T> = std::result::Result<T, SolutionError>;
+
+/// Invalid part of the input: where it is, and what was expected instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    /// Line of the error, from 1.
+    pub line: usize,
+    /// Column of the error, in characters from 1.
+    pub column: usize,
+    /// Bytes of the input that are invalid.
+    pub span: Range<usize>,
+    pub text: String,
+    pub expected: Option<String>,
+    /// The line of the input where the error starts, for [`InputError::render`].
+    pub source: String,
+}
+
+impl InputError {
+    /// Error for the `span` bytes of `input`.
+    pub fn new(input: &str, span: Range<usize>, expected: Option<String>) -> Self {
+        let before = &input[..span.start];
+        let start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let end = input[span.start..]
+            .find('\n')
+            .map_or(input.len(), |newline| span.start + newline);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: input[start..span.start].chars().count() + 1,
+            text: input[span.clone()].to_owned(),
+            source: input[start..end].trim_end_matches('\r').to_owned(),
+            span,
+            expected,
+        }
+    }
+
+    /// The error, then its line with a caret under the invalid text:
+    ///
+    /// ```text
+    /// expected i32, found 'x' at line 2, column 3
+    ///   |
+    /// 2 | 1,x
+    ///   |   ^
+    /// ```
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let width = self.text.lines().next().unwrap_or_default().chars().count();
+
+        format!(
+            "{self}\n{margin} |\n{number} | {}\n{margin} | {}{}",
+            self.source,
+            " ".repeat(self.column - 1),
+            "^".repeat(width.max(1))
+        )
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            line, column, text, ..
+        } = self;
+
+        match &self.expected {
+            Some(expected) if text.is_empty() => write!(f, "expected {expected}")?,
+            Some(expected) => write!(f, "expected {expected}, found '{text}'")?,
+            None => write!(f, "invalid input '{text}'")?,
+        }
+
+        write!(f, " at line {line}, column {column}")
+    }
+}
+
+/// Parses `text`, a slice of `input`, reporting where it is when invalid.
+pub fn parse_in<T: FromStr>(input: &str, text: &str) -> Result<T> {
+    text.parse()
+        .map_err(|_| SolutionError::expected(input, text, std::any::type_name::<T>()))
+}
+
+/// Parses each line of `input`, locating the errors of `T::from_str` in `input`.
+pub fn parse_lines<T>(input: &str) -> impl Iterator<Item = Result<T>> + '_
+where
+    T: FromStr<Err = SolutionError>,
+{
+    input.lines().map(|line| {
+        line.parse()
+            .map_err(|err: SolutionError| err.within(input, line))
+    })
+}
+
+/// A part of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Answer to a part, or why there is none, and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: Result<String>,
+    pub time: Duration,
+}
+
+/// Outcome of [`Solution::solve`]; parts that were not requested are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub parse: Duration,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Report {
+    pub fn total(&self) -> Duration {
+        [&self.part1, &self.part2]
+            .into_iter()
+            .flatten()
+            .fold(self.parse, |total, answer| total + answer.time)
+    }
+}
+
+/// Path of a day's input under `dir`: `<dir>/inputs/dayNN.txt`.
+pub fn input_path(dir: &str, day: u8) -> String {
+    format!("{dir}/inputs/day{day:02}.txt")
+}
+
+pub fn read_input(dir: &str, day: u8) -> Result<String> {
+    read_source(&input_path(dir, day))
+}
+
+/// Reads a file, or stdin when `path` is `-`.
+pub fn read_source(path: &str) -> Result<String> {
+    let input = match path {
+        "-" => std::io::read_to_string(std::io::stdin()),
+        path => std::fs::read_to_string(path),
+    };
+
+    input.map_err(|_| SolutionError::MissingInput(path.to_owned()))
+}
+
+/// Reads the input given with `--input <file>`, defaulting to `<dir>/inputs/dayNN.txt`.
+///
+/// Other arguments are ignored, days with their own `main` may use them.
+pub fn input_from_args(
+    args: impl IntoIterator<Item = String>,
+    dir: &str,
+    day: u8,
+) -> Result<String> {
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--input" {
+            let path = args.next().ok_or_else(|| {
+                SolutionError::InvalidArgument("--input expects a file, or - for stdin".to_owned())
+            })?;
+
+            return read_source(&path);
+        }
+    }
+
+    read_input(dir, day)
+}
+
+/// The input as the parsers expect it: without a byte order mark, with `\n` line endings and
+/// without trailing blank lines. The last line keeps its line ending, if any.
+///
+/// ```
+/// use aoc::solution::normalize;
+///
+/// assert_eq!("1\n\n2\n", normalize("\u{feff}1\r\n\r\n2\r\n\r\n  \r\n"));
+/// assert_eq!("1\n2", normalize("1\n2"));
+/// ```
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let content = input.trim_end().len();
+    let input = match input[content..].find('\n') {
+        Some(newline) => &input[..content + newline + 1],
+        None => input,
+    };
+
+    match input.contains('\r') {
+        true => Cow::Owned(input.replace("\r\n", "\n")),
+        false => Cow::Borrowed(input),
+    }
+}
+
+/// The input with `\n`, then `\r\n` line endings, so that tests cover both.
+#[doc(hidden)]
+pub fn line_endings(input: &str) -> [(&'static str, String); 2] {
+    let lf = input.replace("\r\n", "\n");
+    let crlf = lf.replace('\n', "\r\n");
+
+    [("LF", lf), ("CRLF", crlf)]
+}
+
+fn bench<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+/// Times the parsing, then each of the requested parts.
+pub(crate) fn timed<I>(
+    parse: impl FnOnce() -> Result<I>,
+    part1: impl FnOnce(&I) -> Result<String>,
+    part2: impl FnOnce(&I) -> Result<String>,
+    parts: &[Part],
+) -> Result<Report> {
+    let (input, parse) = bench(parse);
+    let input = input?;
+
+    let part1 = parts.contains(&Part::One).then(|| {
+        let (value, time) = bench(|| part1(&input));
+
+        Answer { value, time }
+    });
+    let part2 = parts.contains(&Part::Two).then(|| {
+        let (value, time) = bench(|| part2(&input));
+
+        Answer { value, time }
+    });
+
+    Ok(Report {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn as_ms(duration: Duration) -> f32 {
+    duration.as_secs_f32() * 1000.
+}
+
+pub trait Solution {
+    const TITLE: &'static str;
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+    type P1: Display;
+    type P2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::P1>;
+    fn part2(input: &Self::Input) -> Result<Self::P2>;
+
+    /// Parses the [`normalize`]d input and times the requested parts.
+    fn solve(input: &str, parts: &[Part]) -> Result<Report> {
+        timed(
+            || Self::parse(&normalize(input)),
+            |input| Self::part1(input).map(|answer| answer.to_string()),
+            |input| Self::part2(input).map(|answer| answer.to_string()),
+            parts,
+        )
+    }
+
+    /// Prints the answers, then returns the first error of a part, if any.
+    fn run(input: &str) -> Result<()> {
+        Self::run_against(input, &Answers::default())
+    }
+
+    /// Like [`Solution::run`], also failing on answers that differ from the recorded ones.
+    fn run_against(input: &str, answers: &Answers) -> Result<()> {
+        println!("{} Day {:02}: {}", Self::YEAR, Self::DAY, Self::TITLE);
+
+        let report = Self::solve(input, &Part::ALL)?;
+        let total = report.total();
+        let mut error = None;
+
+        println!("\tParsing\t\t\t- in {:.4}ms", as_ms(report.parse));
+        for (part, answer) in [(Part::One, report.part1), (Part::Two, report.part2)] {
+            match answer.map(|answer| (answer.value, answer.time)) {
+                Some((Ok(value), time)) => {
+                    println!("\tPart {part}: '{value}'\t- in {:.4}ms", as_ms(time));
+
+                    if let Err(err) = answers.check(Self::DAY, part, &value) {
+                        error.get_or_insert(err);
+                    }
+                }
+                Some((Err(SolutionError::NoAnswer), _)) | None => {}
+                Some((Err(err), _)) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+
+        println!("\tTotal Time:\t\t{:.4}ms", as_ms(total));
+
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Compares the answers to the expected ones, skipping parts given as `None`.
+    ///
+    /// The input is checked with both `\n` and `\r\n` line endings.
+    fn test(input: &str, part1: Option<Self::P1>, part2: Option<Self::P2>) {
+        let mut count = 0;
+
+        for (endings, input) in line_endings(input) {
+            let input = Self::parse(&normalize(&input))
+                .unwrap_or_else(|err| panic!("{err} ({endings} line endings)"));
+
+            if let (Some(r1), Some(expected)) = (answered(Self::part1(&input)), &part1) {
+                assert_eq!(
+                    expected.to_string(),
+                    r1.to_string(),
+                    "{endings} line endings"
+                );
+                count += 1;
+            }
+
+            if let (Some(r2), Some(expected)) = (answered(Self::part2(&input)), &part2) {
+                assert_eq!(
+                    expected.to_string(),
+                    r2.to_string(),
+                    "{endings} line endings"
+                );
+                count += 1;
+            }
+        }
+
+        println!(
+            "{} Day {:02}: {} - {}/4 tests passed!",
+            Self::YEAR,
+            Self::DAY,
+            Self::TITLE,
+            count
+        );
+    }
+
+    fn test_part1(input: &str) -> Result<()> {
+        let input = Self::parse(&normalize(input))?;
+
+        match Self::part1(&input) {
+            Ok(answer) => println!("Day {:02} example, part 1: '{answer}'", Self::DAY),
+            Err(SolutionError::NoAnswer) => {
+                println!("Day {:02} example, part 1: no answer", Self::DAY)
+            }
+            Err(err) => return Err(err),
+        }
+
+        Ok(())
+    }
+
+    fn test_part2(input: &str) -> Result<()> {
+        let input = Self::parse(&normalize(input))?;
+
+        match Self::part2(&input) {
+            Ok(answer) => println!("Day {:02} example, part 2: '{answer}'", Self::DAY),
+            Err(SolutionError::NoAnswer) => {
+                println!("Day {:02} example, part 2: no answer", Self::DAY)
+            }
+            Err(err) => return Err(err),
+        }
+
+        Ok(())
+    }
+}
+
+/// The answer of a part, `None` for [`SolutionError::NoAnswer`]; panics on other errors.
+fn answered<T>(answer: Result<T>) -> Option<T> {
+    match answer {
+        Ok(answer) => Some(answer),
+        Err(SolutionError::NoAnswer) => None,
+        Err(err) => panic!("{err}"),
+    }
+}
+
+/// Asserts a part against its expected answer, for the input with each of the [`line_endings`].
+/// `None` skips the check.
+#[doc(hidden)]
+pub fn check<I, T, E>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<I>,
+    part: impl Fn(&I) -> Result<T>,
+    expected: Option<E>,
+) where
+    T: PartialEq<E> + Debug,
+    E: Debug,
+{
+    for (endings, input) in line_endings(input) {
+        let input = parse(&normalize(&input)).unwrap_or_else(|err| {
+            panic!("failed to parse the input: {err} ({endings} line endings)")
+        });
+
+        if let Some(expected) = &expected {
+            let actual =
+                part(&input).unwrap_or_else(|err| panic!("{err} ({endings} line endings)"));
+
+            assert_eq!(&actual, expected, "{endings} line endings");
+        }
+    }
+}